@@ -15,7 +15,7 @@
 use super::{LabeledPolynomial, PolynomialInfo};
 use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10};
 use snarkvm_curves::PairingEngine;
-use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
+use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
 
 use hashbrown::HashMap;
@@ -679,3 +679,24 @@ impl<E: PairingEngine> ToBytes for BatchLCProof<E> {
         CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize struct"))
     }
 }
+
+/// The elements accumulated by a batch check, which are verified with a single product of pairings.
+#[derive(Clone, Debug)]
+pub struct BatchCheckElems<E: PairingEngine> {
+    pub(super) combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+    pub(super) combined_witness: E::G1Projective,
+    pub(super) combined_adjusted_witness: E::G1Projective,
+}
+
+impl<E: PairingEngine> BatchCheckElems<E> {
+    /// Adds the elements of `other`, scaled by `randomizer`, into these elements.
+    /// Since the pairing check is linear in each element, the combined check passes
+    /// if both checks pass, and fails with high probability over the choice of `randomizer` otherwise.
+    pub fn accumulate(&mut self, other: Self, randomizer: E::Fr) {
+        for (degree_bound, comm) in other.combined_comms {
+            *self.combined_comms.entry(degree_bound).or_insert_with(E::G1Projective::zero) += comm * randomizer;
+        }
+        self.combined_witness += other.combined_witness * randomizer;
+        self.combined_adjusted_witness += other.combined_adjusted_witness * randomizer;
+    }
+}
@@ -348,6 +348,22 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let elems = Self::accumulate_batch_check(vk, commitments, query_set, values, proof, fs_rng)?;
+        Self::check_elems(vk, elems)
+    }
+
+    /// Accumulates the elements of a batch check, without performing the final pairing check.
+    pub fn accumulate_batch_check<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<BatchCheckElems<E>>
     where
         Commitment<E>: 'a,
     {
@@ -404,9 +420,8 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
         }
 
-        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
         end_timer!(batch_check_time);
-        result.map_err(Into::into)
+        Ok(BatchCheckElems { combined_comms, combined_witness, combined_adjusted_witness })
     }
 
     pub fn open_combinations<'a>(
@@ -482,6 +497,24 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let elems =
+            Self::accumulate_combinations(vk, linear_combinations, commitments, query_set, evaluations, proof, fs_rng)?;
+        Self::check_elems(vk, elems)
+    }
+
+    /// Accumulates the elements of a check on the linear combinations, without performing the final pairing check.
+    pub fn accumulate_combinations<'a>(
+        vk: &UniversalVerifier<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<BatchCheckElems<E>>
     where
         Commitment<E>: 'a,
     {
@@ -539,7 +572,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Self::accumulate_batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
     }
 }
 
@@ -633,12 +666,9 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Ok(())
     }
 
-    fn check_elems(
-        vk: &UniversalVerifier<E>,
-        combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
-        combined_witness: E::G1Projective,
-        combined_adjusted_witness: E::G1Projective,
-    ) -> Result<bool> {
+    /// Checks the accumulated elements of one or more batch checks with a single product of pairings.
+    pub fn check_elems(vk: &UniversalVerifier<E>, elems: BatchCheckElems<E>) -> Result<bool> {
+        let BatchCheckElems { combined_comms, combined_witness, combined_adjusted_witness } = elems;
         let check_time = start_timer!(|| "Checking elems");
         let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
         let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
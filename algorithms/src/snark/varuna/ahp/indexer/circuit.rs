@@ -35,6 +35,11 @@ use hex::FromHex;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{serialize::*, SerializationError};
 
+/// The identifier of an indexed circuit.
+///
+/// Circuit IDs are totally ordered by the lexicographic order of their bytes.
+/// The prover and verifier both rely on this order to lay out the per-circuit components of a proof,
+/// so it must not depend on the order in which circuits are supplied.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Ord, PartialOrd, CanonicalSerialize, CanonicalDeserialize)]
 pub struct CircuitId(pub [u8; 32]);
 
@@ -28,6 +28,14 @@ pub use circuit_verifying_key::*;
 pub(super) mod proof;
 pub use proof::*;
 
+/// The Varuna zkSNARK proof metadata.
+pub(super) mod proof_metadata;
+pub use proof_metadata::*;
+
+/// The Varuna proof batch accumulator.
+pub(super) mod proof_batch_accumulator;
+pub use proof_batch_accumulator::*;
+
 /// A test circuit.
 #[cfg(any(test, feature = "test"))]
 pub(super) mod test_circuit;
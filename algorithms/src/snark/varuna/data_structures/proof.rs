@@ -20,15 +20,20 @@ use crate::{
 
 use ahp::prover::{FourthMessage, ThirdMessage};
 use snarkvm_curves::PairingEngine;
-use snarkvm_fields::PrimeField;
+use snarkvm_fields::{PrimeField, Zero};
 use snarkvm_utilities::{
     error,
     io::{self, Read, Write},
     serialize::*,
+    string::String,
     FromBytes,
+    FromBytesDeserializer,
     ToBytes,
+    ToBytesSerializer,
 };
 
+use core::{fmt, str::FromStr};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::BTreeMap;
 
 #[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
@@ -53,6 +58,75 @@ pub struct Commitments<E: PairingEngine> {
 }
 
 impl<E: PairingEngine> Commitments<E> {
+    /// Construct new commitments, ensuring there is a witness commitment for each instance,
+    /// and a `g_a`, `g_b`, and `g_c` commitment for each circuit.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_checked(
+        witness_commitments: Vec<WitnessCommitments<E>>,
+        mask_poly: Option<sonic_pc::Commitment<E>>,
+        h_0: sonic_pc::Commitment<E>,
+        g_1: sonic_pc::Commitment<E>,
+        h_1: sonic_pc::Commitment<E>,
+        g_a_commitments: Vec<sonic_pc::Commitment<E>>,
+        g_b_commitments: Vec<sonic_pc::Commitment<E>>,
+        g_c_commitments: Vec<sonic_pc::Commitment<E>>,
+        h_2: sonic_pc::Commitment<E>,
+        batch_sizes: &BTreeMap<CircuitId, usize>,
+    ) -> Result<Self, SNARKError> {
+        let total_instances = batch_sizes
+            .values()
+            .try_fold(0usize, |acc, &size| acc.checked_add(size))
+            .ok_or(SNARKError::BatchSizeMismatch)?;
+        if witness_commitments.len() != total_instances {
+            return Err(SNARKError::BatchSizeMismatch);
+        }
+        for comms in [&g_a_commitments, &g_b_commitments, &g_c_commitments] {
+            if comms.len() != batch_sizes.len() {
+                return Err(SNARKError::BatchSizeMismatch);
+            }
+        }
+        Ok(Self {
+            witness_commitments,
+            mask_poly,
+            h_0,
+            g_1,
+            h_1,
+            g_a_commitments,
+            g_b_commitments,
+            g_c_commitments,
+            h_2,
+        })
+    }
+
+    /// Returns the witness commitments for the instance at the given index, or `None` if it is out of bounds.
+    pub fn witness(&self, index: usize) -> Option<&WitnessCommitments<E>> {
+        self.witness_commitments.get(index)
+    }
+
+    /// Returns an iterator over the commitments to the `w` polynomial of each instance.
+    pub fn iter_w(&self) -> impl '_ + ExactSizeIterator<Item = &sonic_pc::Commitment<E>> {
+        self.witness_commitments.iter().map(|commitments| &commitments.w)
+    }
+
+    /// Returns every commitment, in the order in which they are serialized.
+    pub fn all_commitments(&self) -> Vec<&sonic_pc::Commitment<E>> {
+        let mut commitments = Vec::with_capacity(
+            self.witness_commitments.len()
+                + self.g_a_commitments.len()
+                + self.g_b_commitments.len()
+                + self.g_c_commitments.len()
+                + 5,
+        );
+        commitments.extend(self.iter_w());
+        commitments.extend(self.mask_poly.as_ref());
+        commitments.extend([&self.h_0, &self.g_1, &self.h_1]);
+        commitments.extend(&self.g_a_commitments);
+        commitments.extend(&self.g_b_commitments);
+        commitments.extend(&self.g_c_commitments);
+        commitments.push(&self.h_2);
+        commitments
+    }
+
     fn serialize_with_mode<W: snarkvm_utilities::Write>(
         &self,
         mut writer: W,
@@ -88,9 +162,12 @@ impl<E: PairingEngine> Commitments<E> {
         compress: Compress,
         validate: Validate,
     ) -> Result<Self, snarkvm_utilities::SerializationError> {
+        // Read the witness commitments one at a time, so that memory is only allocated for commitments that are read.
         let mut w = Vec::new();
         for batch_size in batch_sizes {
-            w.extend(deserialize_vec_without_len(&mut reader, compress, validate, *batch_size)?);
+            for _ in 0..*batch_size {
+                w.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
+            }
         }
         Ok(Commitments {
             witness_commitments: w,
@@ -163,7 +240,7 @@ impl<F: PrimeField> Evaluations<F> {
     pub(crate) fn from_map(
         map: &std::collections::BTreeMap<String, F>,
         batch_sizes: BTreeMap<CircuitId, usize>,
-    ) -> Self {
+    ) -> Result<Self, SNARKError> {
         let mut g_a_evals = Vec::with_capacity(batch_sizes.len());
         let mut g_b_evals = Vec::with_capacity(batch_sizes.len());
         let mut g_c_evals = Vec::with_capacity(batch_sizes.len());
@@ -181,25 +258,81 @@ impl<F: PrimeField> Evaluations<F> {
                 g_c_evals.push(*value);
             }
         }
-        Self { g_1_eval: map["g_1"], g_a_evals, g_b_evals, g_c_evals }
+
+        // Extract the evaluation of `g_1` separately, so the above does not depend on the iteration order.
+        let g_1_eval =
+            *map.get("g_1").ok_or_else(|| SNARKError::Message("Missing the evaluation of g_1".to_string()))?;
+        Ok(Self { g_1_eval, g_a_evals, g_b_evals, g_c_evals })
     }
 
+    /// Returns the evaluation for the given label, or `None` if the label is malformed.
+    /// Aside from `g_1`, labels must be witness labels of the form `circuit_{circuit_id}_{poly}_{index}`.
     pub(crate) fn get(&self, circuit_index: usize, label: &str) -> Option<F> {
         if label == "g_1" {
             return Some(self.g_1_eval);
         }
 
-        if label.contains("g_a") {
+        // Ensure the label ends in a numeric suffix.
+        let (prefix, index) = label.rsplit_once('_')?;
+        if index.is_empty() || !index.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+
+        if prefix.ends_with("_g_a") {
             self.g_a_evals.get(circuit_index).copied()
-        } else if label.contains("g_b") {
+        } else if prefix.ends_with("_g_b") {
             self.g_b_evals.get(circuit_index).copied()
-        } else if label.contains("g_c") {
+        } else if prefix.ends_with("_g_c") {
             self.g_c_evals.get(circuit_index).copied()
         } else {
             None
         }
     }
 
+    /// Returns the evaluations of `g_a`, `g_b`, and `g_c` for the circuit at the given index,
+    /// or `None` if the index is out of bounds.
+    pub fn evals_for_circuit(&self, circuit_index: usize) -> Option<[F; 3]> {
+        Some([
+            *self.g_a_evals.get(circuit_index)?,
+            *self.g_b_evals.get(circuit_index)?,
+            *self.g_c_evals.get(circuit_index)?,
+        ])
+    }
+
+    /// Returns the evaluations of the given polynomial for every circuit, ordered by circuit index.
+    /// This avoids matching on the label once per circuit when iterating over a whole batch.
+    /// The label must be exactly one of `g_1`, `g_a`, `g_b`, or `g_c`.
+    pub fn evals_for_label(&self, label: &str) -> Option<&[F]> {
+        match label {
+            "g_1" => Some(core::slice::from_ref(&self.g_1_eval)),
+            "g_a" => Some(&self.g_a_evals),
+            "g_b" => Some(&self.g_b_evals),
+            "g_c" => Some(&self.g_c_evals),
+            _ => None,
+        }
+    }
+
+    /// Appends the per-circuit evaluations of `other` to `self`, for use when combining proofs.
+    /// As `g_1` is shared across circuits, both evaluations of `g_1` must match.
+    pub fn try_append(&mut self, other: &Self) -> Result<(), SNARKError> {
+        if self.g_1_eval != other.g_1_eval {
+            return Err(SNARKError::Message("Mismatched evaluations of g_1".to_string()));
+        }
+        self.g_a_evals.extend_from_slice(&other.g_a_evals);
+        self.g_b_evals.extend_from_slice(&other.g_b_evals);
+        self.g_c_evals.extend_from_slice(&other.g_c_evals);
+        Ok(())
+    }
+
+    /// Ensures the evaluation of `g_1` is nonzero, as a zero evaluation usually indicates a malformed proof.
+    /// The evaluations of `g_a`, `g_b`, and `g_c` are not checked, as zero is a valid evaluation for each of them.
+    pub fn check_nonzero_g1(&self) -> Result<(), SNARKError> {
+        match self.g_1_eval.is_zero() {
+            true => Err(SNARKError::Message("The evaluation of g_1 is zero".to_string())),
+            false => Ok(()),
+        }
+    }
+
     pub fn to_field_elements(&self) -> Vec<F> {
         let mut result = Vec::with_capacity(1 + self.g_a_evals.len() + self.g_b_evals.len() + self.g_c_evals.len());
         result.push(self.g_1_eval);
@@ -219,6 +352,13 @@ impl<F: PrimeField> Valid for Evaluations<F> {
     }
 }
 
+/// The maximum number of instances, and of circuits, that may be proven in a single proof.
+///
+/// This is far above the largest batch proven in practice, as an execution proves at most 32 transitions,
+/// yet bounds the memory a malicious proof can force deserialization to allocate to tens of megabytes,
+/// as each instance and each circuit contributes at most a few hundred bytes of commitments and messages.
+pub const MAX_BATCH_SIZE: usize = 1 << 16;
+
 /// A zkSNARK proof.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Proof<E: PairingEngine> {
@@ -243,6 +383,7 @@ pub struct Proof<E: PairingEngine> {
 
 impl<E: PairingEngine> Proof<E> {
     /// Construct a new proof.
+    /// The batch sizes are laid out in the order of their circuit IDs.
     pub fn new(
         batch_sizes: BTreeMap<CircuitId, usize>,
         commitments: Commitments<E>,
@@ -252,13 +393,190 @@ impl<E: PairingEngine> Proof<E> {
         pc_proof: sonic_pc::BatchLCProof<E>,
     ) -> Result<Self, SNARKError> {
         let batch_sizes: Vec<usize> = batch_sizes.into_values().collect();
-        Ok(Self { batch_sizes, commitments, evaluations, third_msg, fourth_msg, pc_proof })
+        let proof = Self { batch_sizes, commitments, evaluations, third_msg, fourth_msg, pc_proof };
+        // Ensure the batch sizes are consistent with the commitments, evaluations, and messages.
+        proof.check_batch_sizes()?;
+        Ok(proof)
     }
 
     pub fn batch_sizes(&self) -> &[usize] {
         &self.batch_sizes
     }
 
+    /// Returns an iterator over the `(circuit_index, batch_size)` of each circuit,
+    /// in the order of their circuit IDs, which is also the order of the per-circuit components of the proof.
+    pub fn iter_batches(&self) -> impl '_ + ExactSizeIterator<Item = (usize, usize)> {
+        // Ensure the per-circuit evaluations align with the batch sizes.
+        let g_evals = [&self.evaluations.g_a_evals, &self.evaluations.g_b_evals, &self.evaluations.g_c_evals];
+        debug_assert!(g_evals.iter().all(|evals| evals.len() == self.batch_sizes.len()));
+        self.batch_sizes.iter().copied().enumerate()
+    }
+
+    /// Returns the total number of instances proven across all circuits.
+    /// The count saturates at `usize::MAX`, as the batch sizes are not checked for consistency here.
+    pub fn total_instances(&self) -> usize {
+        self.batch_sizes.iter().fold(0usize, |acc, &size| acc.saturating_add(size))
+    }
+
+    /// Returns the number of circuits proven in this proof.
+    pub fn num_circuits(&self) -> usize {
+        self.batch_sizes.len()
+    }
+
+    /// Returns the serialized size of the proof in bytes, as `(compressed, uncompressed)`.
+    pub fn byte_sizes(&self) -> (usize, usize) {
+        (self.serialized_size(Compress::Yes), self.serialized_size(Compress::No))
+    }
+
+    /// Returns the serialized size of the proof in bytes, excluding its evaluations.
+    pub fn without_evaluations_size(&self, compress: Compress) -> usize {
+        self.serialized_size(compress).saturating_sub(self.evaluations.serialized_size(compress))
+    }
+
+    /// Returns a short description of the shape and size of the proof, without any group elements.
+    pub fn summary(&self) -> String {
+        format!(
+            "Proof {{ circuits: {}, instances: {}, witness_commitments: {}, g_a_commitments: {}, g_b_commitments: {}, g_c_commitments: {}, mask_poly: {}, compressed_size: {} }}",
+            self.num_circuits(),
+            self.total_instances(),
+            self.commitments.witness_commitments.len(),
+            self.commitments.g_a_commitments.len(),
+            self.commitments.g_b_commitments.len(),
+            self.commitments.g_c_commitments.len(),
+            self.commitments.mask_poly.is_some(),
+            self.byte_sizes().0,
+        )
+    }
+
+    /// Returns the commitment to the masking polynomial, if one exists.
+    pub fn mask_commitment(&self) -> Option<&sonic_pc::Commitment<E>> {
+        self.commitments.mask_poly.as_ref()
+    }
+
+    /// Returns the commitments to `g_1`, `h_1`, and `h_2`, which are shared across the batch.
+    pub fn shared_commitments(&self) -> (&sonic_pc::Commitment<E>, &sonic_pc::Commitment<E>, &sonic_pc::Commitment<E>) {
+        (&self.commitments.g_1, &self.commitments.h_1, &self.commitments.h_2)
+    }
+
+    /// Returns the proof if it contains exactly one circuit with a single instance.
+    pub fn try_into_single(self) -> Result<Self, SNARKError> {
+        match self.batch_sizes.as_slice() {
+            [1] => Ok(self),
+            _ => Err(SNARKError::BatchSizeMismatch),
+        }
+    }
+
+    /// Merges two proofs over disjoint sets of circuits into a single proof,
+    /// by concatenating their per-circuit and per-instance components.
+    ///
+    /// The components shared across the batch must match, or the merge errors.
+    /// These are the commitments to `mask_poly`, `h_0`, `g_1`, `h_1`, and `h_2`,
+    /// the evaluation of `g_1`, and the evaluation proof.
+    ///
+    /// The circuits of `other` are appended after those of `self`, so the batches of the merged proof
+    /// are no longer laid out in the order of their circuit IDs, as `Proof::new` and the verifier expect.
+    /// Moreover, the Fiat-Shamir transcript of each proof binds only its own circuits, so the merged proof
+    /// is not verifiable under Varuna; it is only a container for presenting both proofs together.
+    pub fn try_merge(mut self, other: Self) -> Result<Self, SNARKError> {
+        let (commitments, other_commitments) = (&self.commitments, &other.commitments);
+        if commitments.mask_poly != other_commitments.mask_poly
+            || commitments.h_0 != other_commitments.h_0
+            || commitments.g_1 != other_commitments.g_1
+            || commitments.h_1 != other_commitments.h_1
+            || commitments.h_2 != other_commitments.h_2
+        {
+            return Err(SNARKError::Message("Cannot merge proofs with different shared commitments".to_string()));
+        }
+        if self.evaluations.g_1_eval != other.evaluations.g_1_eval {
+            return Err(SNARKError::Message("Cannot merge proofs with different shared evaluations".to_string()));
+        }
+        if self.pc_proof != other.pc_proof {
+            return Err(SNARKError::Message("Cannot merge proofs with different evaluation proofs".to_string()));
+        }
+        // Ensure both proofs are internally consistent, before concatenating their components.
+        self.check_batch_sizes()?;
+        other.check_batch_sizes()?;
+
+        self.batch_sizes.extend(other.batch_sizes);
+        self.commitments.witness_commitments.extend(other.commitments.witness_commitments);
+        self.commitments.g_a_commitments.extend(other.commitments.g_a_commitments);
+        self.commitments.g_b_commitments.extend(other.commitments.g_b_commitments);
+        self.commitments.g_c_commitments.extend(other.commitments.g_c_commitments);
+        self.evaluations.g_a_evals.extend(other.evaluations.g_a_evals);
+        self.evaluations.g_b_evals.extend(other.evaluations.g_b_evals);
+        self.evaluations.g_c_evals.extend(other.evaluations.g_c_evals);
+        self.third_msg.sums.extend(other.third_msg.sums);
+        self.fourth_msg.sums.extend(other.fourth_msg.sums);
+        Ok(self)
+    }
+
+    /// Returns a proof retaining only the instances at the given indices, which index into all instances
+    /// of the proof in order, and must be strictly increasing. Circuits without a retained instance are removed.
+    ///
+    /// The Fiat-Shamir transcript binds every instance of the original proof, so the resulting proof
+    /// is not verifiable under Varuna; it is only meaningful for a scheme which supports instance subsetting.
+    pub fn subset(&self, instance_indices: &[usize]) -> Result<Self, SNARKError> {
+        self.check_batch_sizes()?;
+        if instance_indices.is_empty() {
+            return Err(SNARKError::EmptyBatch);
+        }
+        if instance_indices.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(SNARKError::Message("The instance indices must be strictly increasing".to_string()));
+        }
+        if instance_indices.iter().any(|&index| index >= self.total_instances()) {
+            return Err(SNARKError::Message("The instance indices must be within the batch".to_string()));
+        }
+
+        // Assign each retained instance to its circuit.
+        let mut third_msg_sums = vec![Vec::new(); self.num_circuits()];
+        let mut witness_commitments = Vec::with_capacity(instance_indices.len());
+        let mut indices = instance_indices.iter().peekable();
+        let mut offset = 0;
+        for (circuit_index, &batch_size) in self.batch_sizes.iter().enumerate() {
+            while let Some(&index) = indices.next_if(|&&index| index < offset + batch_size) {
+                witness_commitments.push(self.commitments.witness_commitments[index].clone());
+                third_msg_sums[circuit_index].push(self.third_msg.sums[circuit_index][index - offset].clone());
+            }
+            offset += batch_size;
+        }
+
+        // Retain the per-circuit components of the circuits with a retained instance.
+        let retained = third_msg_sums.iter().map(|sums| !sums.is_empty()).collect::<Vec<_>>();
+        fn retain<T: Clone>(items: &[T], retained: &[bool]) -> Vec<T> {
+            items.iter().zip(retained).filter(|(_, &keep)| keep).map(|(item, _)| item.clone()).collect()
+        }
+        let proof = Self {
+            batch_sizes: third_msg_sums.iter().map(Vec::len).filter(|&batch_size| batch_size > 0).collect(),
+            commitments: Commitments {
+                witness_commitments,
+                g_a_commitments: retain(&self.commitments.g_a_commitments, &retained),
+                g_b_commitments: retain(&self.commitments.g_b_commitments, &retained),
+                g_c_commitments: retain(&self.commitments.g_c_commitments, &retained),
+                ..self.commitments.clone()
+            },
+            evaluations: Evaluations {
+                g_1_eval: self.evaluations.g_1_eval,
+                g_a_evals: retain(&self.evaluations.g_a_evals, &retained),
+                g_b_evals: retain(&self.evaluations.g_b_evals, &retained),
+                g_c_evals: retain(&self.evaluations.g_c_evals, &retained),
+            },
+            third_msg: ThirdMessage { sums: third_msg_sums.into_iter().filter(|sums| !sums.is_empty()).collect() },
+            fourth_msg: FourthMessage { sums: retain(&self.fourth_msg.sums, &retained) },
+            pc_proof: self.pc_proof.clone(),
+        };
+        proof.check_batch_sizes()?;
+        Ok(proof)
+    }
+
+    /// Ensures that `original_bytes` is the canonical encoding of the proof.
+    pub fn assert_canonical(&self, original_bytes: &[u8]) -> Result<(), SNARKError> {
+        let bytes = self.to_bytes_le().map_err(|_| SNARKError::Message("Failed to serialize the proof".to_string()))?;
+        match bytes == original_bytes {
+            true => Ok(()),
+            false => Err(SNARKError::Message("The proof is not canonically encoded".to_string())),
+        }
+    }
+
     /// Check that the number of messages is consistent with our batch size
     pub fn check_batch_sizes(&self) -> Result<(), SNARKError> {
         let total_instances = self
@@ -297,6 +615,70 @@ impl<E: PairingEngine> Proof<E> {
     }
 }
 
+/// A builder to incrementally assemble a zkSNARK proof, as its components are produced by the prover.
+#[derive(Clone, Debug)]
+pub struct ProofBuilder<E: PairingEngine> {
+    batch_sizes: Option<BTreeMap<CircuitId, usize>>,
+    commitments: Option<Commitments<E>>,
+    evaluations: Option<Evaluations<E::Fr>>,
+    msgs: Option<(ThirdMessage<E::Fr>, FourthMessage<E::Fr>)>,
+    pc_proof: Option<sonic_pc::BatchLCProof<E>>,
+}
+
+impl<E: PairingEngine> Default for ProofBuilder<E> {
+    fn default() -> Self {
+        Self { batch_sizes: None, commitments: None, evaluations: None, msgs: None, pc_proof: None }
+    }
+}
+
+impl<E: PairingEngine> ProofBuilder<E> {
+    /// Initializes a new proof builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of instances being proven for each circuit.
+    pub fn with_batch_sizes(mut self, batch_sizes: BTreeMap<CircuitId, usize>) -> Self {
+        self.batch_sizes = Some(batch_sizes);
+        self
+    }
+
+    /// Sets the commitments to the prover polynomials.
+    pub fn with_commitments(mut self, commitments: Commitments<E>) -> Self {
+        self.commitments = Some(commitments);
+        self
+    }
+
+    /// Sets the evaluations of the committed polynomials.
+    pub fn with_evaluations(mut self, evaluations: Evaluations<E::Fr>) -> Self {
+        self.evaluations = Some(evaluations);
+        self
+    }
+
+    /// Sets the third and fourth prover messages.
+    pub fn with_msg(mut self, third_msg: ThirdMessage<E::Fr>, fourth_msg: FourthMessage<E::Fr>) -> Self {
+        self.msgs = Some((third_msg, fourth_msg));
+        self
+    }
+
+    /// Sets the evaluation proof from the polynomial commitment.
+    pub fn with_pc_proof(mut self, pc_proof: sonic_pc::BatchLCProof<E>) -> Self {
+        self.pc_proof = Some(pc_proof);
+        self
+    }
+
+    /// Returns the proof, if all of its components have been set.
+    pub fn build(self) -> Result<Proof<E>, SNARKError> {
+        let missing = |component: &str| SNARKError::Message(format!("Missing the {component} of the proof"));
+        let batch_sizes = self.batch_sizes.ok_or_else(|| missing("batch sizes"))?;
+        let commitments = self.commitments.ok_or_else(|| missing("commitments"))?;
+        let evaluations = self.evaluations.ok_or_else(|| missing("evaluations"))?;
+        let (third_msg, fourth_msg) = self.msgs.ok_or_else(|| missing("prover messages"))?;
+        let pc_proof = self.pc_proof.ok_or_else(|| missing("evaluation proof"))?;
+        Proof::new(batch_sizes, commitments, evaluations, third_msg, fourth_msg, pc_proof)
+    }
+}
+
 impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
     fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
         let batch_sizes: Vec<u64> = self.batch_sizes.iter().map(|x| u64::try_from(*x)).collect::<Result<_, _>>()?;
@@ -329,6 +711,21 @@ impl<E: PairingEngine> Valid for Proof<E> {
     fn check(&self) -> Result<(), SerializationError> {
         self.batch_sizes.check()?;
         self.commitments.check()?;
+        // Ensure there is exactly one witness commitment per instance.
+        let total_instances = self
+            .batch_sizes
+            .iter()
+            .try_fold(0usize, |acc, &size| acc.checked_add(size))
+            .ok_or(SerializationError::InvalidData)?;
+        if self.commitments.witness_commitments.len() != total_instances {
+            return Err(SerializationError::InvalidData);
+        }
+        // In zero-knowledge proofs, the witness polynomials are masked, so their commitments must be non-zero.
+        if self.commitments.mask_poly.is_some()
+            && self.commitments.witness_commitments.iter().any(|commitment| commitment.w.0.is_zero())
+        {
+            return Err(SerializationError::InvalidData);
+        }
         self.evaluations.check()?;
         self.third_msg.check()?;
         self.fourth_msg.check()?;
@@ -343,6 +740,17 @@ impl<E: PairingEngine> CanonicalDeserialize for Proof<E> {
         validate: Validate,
     ) -> Result<Self, SerializationError> {
         let batch_sizes: Vec<u64> = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        // Ensure the batch sizes are bounded, before reading any of the batched components.
+        let total_batch_size = batch_sizes.iter().try_fold(0u64, |acc, &size| acc.checked_add(size));
+        match total_batch_size {
+            Some(total_batch_size)
+                if batch_sizes.len() <= MAX_BATCH_SIZE && total_batch_size <= MAX_BATCH_SIZE as u64 => {}
+            _ => return Err(anyhow::anyhow!("Proof exceeds the maximum batch size of {MAX_BATCH_SIZE}").into()),
+        }
+        // Ensure every circuit proves at least one instance.
+        if batch_sizes.is_empty() || batch_sizes.contains(&0) {
+            return Err(SerializationError::InvalidData);
+        }
         let batch_sizes: Vec<usize> = batch_sizes.into_iter().map(|x| x as usize).collect();
         let commitments = Commitments::deserialize_with_mode(&batch_sizes, &mut reader, compress, validate)?;
         let evaluations = Evaluations::deserialize_with_mode(&batch_sizes, &mut reader, compress, validate)?;
@@ -351,14 +759,17 @@ impl<E: PairingEngine> CanonicalDeserialize for Proof<E> {
             .map(|&batch_size| deserialize_vec_without_len(&mut reader, compress, validate, batch_size))
             .collect::<Result<Vec<_>, _>>()?;
         let fourth_msg_sums = deserialize_vec_without_len(&mut reader, compress, validate, batch_sizes.len())?;
-        Ok(Proof {
+        let proof = Proof {
             commitments,
             evaluations,
             third_msg: ThirdMessage { sums: third_msg_sums },
             fourth_msg: FourthMessage { sums: fourth_msg_sums },
             pc_proof: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
             batch_sizes,
-        })
+        };
+        // Ensure the batched components are consistent with the batch sizes.
+        proof.check_batch_sizes().map_err(|_| SerializationError::InvalidData)?;
+        Ok(proof)
     }
 }
 
@@ -374,6 +785,46 @@ impl<E: PairingEngine> FromBytes for Proof<E> {
     }
 }
 
+impl<E: PairingEngine> FromStr for Proof<E> {
+    type Err = anyhow::Error;
+
+    #[inline]
+    fn from_str(proof_hex: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes_le(&hex::decode(proof_hex)?)
+    }
+}
+
+impl<E: PairingEngine> fmt::Display for Proof<E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let proof_hex = hex::encode(self.to_bytes_le().expect("Failed to convert proof to bytes"));
+        write!(f, "{proof_hex}")
+    }
+}
+
+impl<E: PairingEngine> Serialize for Proof<E> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, E: PairingEngine> Deserialize<'de> for Proof<E> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let s: String = Deserialize::deserialize(deserializer)?;
+                FromStr::from_str(&s).map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "proof"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     #![allow(non_camel_case_types)]
@@ -385,11 +836,12 @@ mod test {
             kzg10::{KZGCommitment, KZGProof},
             sonic_pc::BatchProof,
         },
-        snark::varuna::prover::MatrixSums,
+        snark::varuna::{prover::MatrixSums, witness_label, ProofMetadata},
     };
     use snarkvm_curves::{
         bls12_377::{Bls12_377, Fr, G1Affine},
         AffineCurve,
+        ProjectiveCurve,
     };
     use snarkvm_utilities::{TestRng, Uniform};
 
@@ -463,6 +915,47 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_commitments_new_checked() {
+        let batch_sizes = BTreeMap::from([(CircuitId([0u8; 32]), 2), (CircuitId([1u8; 32]), 3)]);
+        let new_checked = |commitments: Commitments<Bls12_377>| {
+            Commitments::new_checked(
+                commitments.witness_commitments,
+                commitments.mask_poly,
+                commitments.h_0,
+                commitments.g_1,
+                commitments.h_1,
+                commitments.g_a_commitments,
+                commitments.g_b_commitments,
+                commitments.g_c_commitments,
+                commitments.h_2,
+                &batch_sizes,
+            )
+        };
+
+        // Check a valid build.
+        let mut commitments = rand_commitments(1, 2, false);
+        commitments.witness_commitments = vec![WitnessCommitments { w: sample_commit() }; 5];
+        assert_eq!(new_checked(commitments.clone()).unwrap(), commitments);
+
+        // Check a mismatched number of witness commitments.
+        let mut candidate = commitments.clone();
+        candidate.witness_commitments.pop();
+        assert!(new_checked(candidate).is_err());
+        // Check a mismatched number of `g_a` commitments.
+        let mut candidate = commitments.clone();
+        candidate.g_a_commitments.push(sample_commit());
+        assert!(new_checked(candidate).is_err());
+        // Check a mismatched number of `g_b` commitments.
+        let mut candidate = commitments.clone();
+        candidate.g_b_commitments.pop();
+        assert!(new_checked(candidate).is_err());
+        // Check a mismatched number of `g_c` commitments.
+        let mut candidate = commitments;
+        candidate.g_c_commitments.clear();
+        assert!(new_checked(candidate).is_err());
+    }
+
     #[test]
     fn test_serializing_evaluations() {
         let rng = &mut TestRng::default();
@@ -484,6 +977,24 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_check_nonzero_g1() {
+        let rng = &mut TestRng::default();
+
+        let mut evaluations: Evaluations<Fr> = rand_evaluations(rng, 2);
+        assert!(evaluations.check_nonzero_g1().is_ok());
+
+        // Check that zeroed evaluations of `g_a`, `g_b`, and `g_c` are accepted.
+        evaluations.g_a_evals[0] = Fr::zero();
+        evaluations.g_b_evals[1] = Fr::zero();
+        evaluations.g_c_evals[0] = Fr::zero();
+        assert!(evaluations.check_nonzero_g1().is_ok());
+
+        // Check that a zeroed evaluation of `g_1` is rejected.
+        evaluations.g_1_eval = Fr::zero();
+        assert!(evaluations.check_nonzero_g1().is_err());
+    }
+
     #[test]
     fn test_serializing_proof() {
         let rng = &mut snarkvm_utilities::rand::TestRng::default();
@@ -510,4 +1021,872 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_new_orders_batch_sizes_by_circuit_id() {
+        let rng = &mut TestRng::default();
+
+        // Construct the components of a proof over 3 circuits, with 1, 2, and 3 instances respectively.
+        let mut commitments = rand_commitments(1, 3, false);
+        commitments.witness_commitments = vec![WitnessCommitments { w: sample_commit() }; 6];
+        let template = Proof {
+            batch_sizes: vec![1, 2, 3],
+            commitments,
+            evaluations: rand_evaluations(rng, 3),
+            third_msg: ThirdMessage {
+                sums: vec![vec![rand_sums(rng)], vec![rand_sums(rng); 2], vec![rand_sums(rng); 3]],
+            },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 3] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        assert!(template.check_batch_sizes().is_ok());
+        let entries = [(CircuitId([2u8; 32]), 3), (CircuitId([0u8; 32]), 1), (CircuitId([1u8; 32]), 2)];
+
+        // Ensure the circuit IDs are ordered lexicographically by their bytes.
+        assert!(CircuitId([0u8; 32]) < CircuitId([1u8; 32]));
+        let mut id = [0u8; 32];
+        id[31] = 1;
+        assert!(CircuitId([0u8; 32]) < CircuitId(id));
+        assert!(CircuitId(id) < CircuitId([1u8; 32]));
+
+        // Ensure the batch sizes do not depend on the order in which the circuits are inserted.
+        let mut proofs = Vec::new();
+        for order in [[0, 1, 2], [2, 1, 0], [1, 2, 0]] {
+            let batch_sizes = order.iter().map(|&i| entries[i]).collect::<BTreeMap<_, _>>();
+            let proof = Proof::new(
+                batch_sizes,
+                template.commitments.clone(),
+                template.evaluations.clone(),
+                template.third_msg.clone(),
+                template.fourth_msg.clone(),
+                template.pc_proof.clone(),
+            )
+            .unwrap();
+            assert_eq!(proof.batch_sizes(), &[1, 2, 3]);
+            assert!(proof.check_batch_sizes().is_ok());
+            proofs.push(proof);
+        }
+        // Ensure repeated construction yields the same proof.
+        assert!(proofs.iter().all(|proof| proof == &proofs[0]));
+
+        // Ensure batch sizes which disagree with the components, once ordered by circuit ID, are rejected.
+        let batch_sizes = [(CircuitId([2u8; 32]), 1), (CircuitId([0u8; 32]), 3), (CircuitId([1u8; 32]), 2)];
+        let result = Proof::new(
+            batch_sizes.into_iter().collect(),
+            template.commitments,
+            template.evaluations,
+            template.third_msg,
+            template.fourth_msg,
+            template.pc_proof,
+        );
+        assert!(matches!(result, Err(SNARKError::BatchSizeMismatch)));
+    }
+
+    #[test]
+    fn test_evaluations_per_circuit() {
+        let rng = &mut TestRng::default();
+
+        let num_circuits = 3;
+        let evaluations = rand_evaluations::<Fr>(rng, num_circuits);
+
+        for circuit_index in 0..num_circuits {
+            let expected = [
+                evaluations.g_a_evals[circuit_index],
+                evaluations.g_b_evals[circuit_index],
+                evaluations.g_c_evals[circuit_index],
+            ];
+            assert_eq!(evaluations.evals_for_circuit(circuit_index), Some(expected));
+            // Ensure the accessors agree with `get`.
+            for (label, eval) in ["g_a", "g_b", "g_c"].iter().zip(expected) {
+                let witness_label = witness_label(CircuitId([0u8; 32]), label, 0);
+                assert_eq!(evaluations.get(circuit_index, &witness_label), Some(eval));
+                assert_eq!(evaluations.evals_for_label(label).unwrap()[circuit_index], eval);
+            }
+        }
+        assert_eq!(evaluations.evals_for_label("g_1"), Some([evaluations.g_1_eval].as_slice()));
+        assert_eq!(evaluations.evals_for_label("g_a"), Some(evaluations.g_a_evals.as_slice()));
+        assert_eq!(evaluations.evals_for_label("g_b"), Some(evaluations.g_b_evals.as_slice()));
+        assert_eq!(evaluations.evals_for_label("g_c"), Some(evaluations.g_c_evals.as_slice()));
+        assert_eq!(evaluations.evals_for_label("h_0"), None);
+        // Ensure labels which merely contain a polynomial name are rejected.
+        assert_eq!(evaluations.evals_for_label(&witness_label(CircuitId([0u8; 32]), "g_a", 0)), None);
+        assert_eq!(evaluations.evals_for_label("circuit_0_g_a_abc"), None);
+        assert_eq!(evaluations.evals_for_label("g_b_g_a"), None);
+
+        // Ensure an out-of-range circuit index is rejected.
+        assert_eq!(evaluations.evals_for_circuit(num_circuits), None);
+        assert_eq!(evaluations.evals_for_circuit(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_evaluations_get_with_malformed_labels() {
+        let rng = &mut TestRng::default();
+
+        let evaluations = rand_evaluations::<Fr>(rng, 2);
+        let circuit_id = CircuitId([7u8; 32]);
+
+        // Ensure well-formed labels are resolved.
+        assert_eq!(evaluations.get(0, "g_1"), Some(evaluations.g_1_eval));
+        assert_eq!(evaluations.get(1, &witness_label(circuit_id, "g_b", 0)), Some(evaluations.g_b_evals[1]));
+        assert_eq!(evaluations.get(2, &witness_label(circuit_id, "g_b", 0)), None);
+
+        // Ensure malformed labels return `None` instead of panicking.
+        for label in [
+            format!("circuit_{circuit_id}_g_a_abc"),
+            format!("circuit_{circuit_id}_g_a_"),
+            format!("circuit_{circuit_id}_g_a_1a"),
+            format!("circuit_{circuit_id}_g_a"),
+            format!("circuit_{circuit_id}_h_0_00000000"),
+            "g_a".to_string(),
+            "g_a_".to_string(),
+            "_".to_string(),
+            "".to_string(),
+        ] {
+            assert_eq!(evaluations.get(0, &label), None, "{label}");
+        }
+    }
+
+    #[test]
+    fn test_evaluations_from_map() {
+        let rng = &mut TestRng::default();
+
+        let circuit_ids = [CircuitId([0u8; 32]), CircuitId([0xffu8; 32])];
+        let batch_sizes = circuit_ids.iter().map(|id| (*id, 1)).collect::<BTreeMap<_, _>>();
+        let expected = rand_evaluations::<Fr>(rng, circuit_ids.len());
+
+        let mut map = BTreeMap::new();
+        for (i, circuit_id) in circuit_ids.iter().enumerate() {
+            map.insert(witness_label(*circuit_id, "g_a", 0), expected.g_a_evals[i]);
+            map.insert(witness_label(*circuit_id, "g_b", 0), expected.g_b_evals[i]);
+            map.insert(witness_label(*circuit_id, "g_c", 0), expected.g_c_evals[i]);
+        }
+
+        // Ensure a map without `g_1` is rejected.
+        assert!(Evaluations::from_map(&map, batch_sizes.clone()).is_err());
+
+        // Ensure the evaluations are collected once `g_1` is present.
+        map.insert("g_1".to_string(), expected.g_1_eval);
+        let candidate = Evaluations::from_map(&map, batch_sizes).unwrap();
+        assert_eq!(candidate, expected);
+    }
+
+    #[test]
+    fn test_proof_metadata() {
+        let rng = &mut TestRng::default();
+
+        let circuit_ids = [CircuitId([2u8; 32]), CircuitId([0u8; 32]), CircuitId([1u8; 32])];
+        let batch_sizes = circuit_ids.iter().map(|id| (*id, 4)).collect::<BTreeMap<_, _>>();
+        let template = Proof {
+            batch_sizes: vec![4; 3],
+            commitments: rand_commitments(4, 3, false),
+            evaluations: rand_evaluations(rng, 3),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 4]; 3] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 3] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        let proof = Proof::new(
+            batch_sizes.clone(),
+            template.commitments,
+            template.evaluations,
+            template.third_msg,
+            template.fourth_msg,
+            template.pc_proof,
+        )
+        .unwrap();
+
+        // Ensure the circuit IDs are laid out in the same order as the proof.
+        let metadata = ProofMetadata::new(batch_sizes);
+        assert!(metadata.check(&proof).is_ok());
+        assert_eq!(
+            metadata.circuit_ids().copied().collect::<Vec<_>>(),
+            [circuit_ids[1], circuit_ids[2], circuit_ids[0]]
+        );
+        assert_eq!(metadata.circuit_index(&circuit_ids[0]), Some(2));
+        assert_eq!(metadata.circuit_index(&CircuitId([3u8; 32])), None);
+
+        // Ensure the metadata survives serialization.
+        let bytes = metadata.to_bytes_le().unwrap();
+        assert_eq!(ProofMetadata::from_bytes_le(&bytes).unwrap(), metadata);
+
+        // Ensure metadata for a different proof is rejected.
+        let mismatched = ProofMetadata::new(circuit_ids.iter().map(|id| (*id, 1)).collect());
+        assert!(mismatched.check(&proof).is_err());
+        assert!(ProofMetadata::new(BTreeMap::new()).check(&proof).is_err());
+    }
+
+    #[test]
+    fn test_witness_commitments_accessors() {
+        let rng = &mut TestRng::default();
+
+        let mut commitments = rand_commitments(2, 3, false);
+        let num_instances = commitments.witness_commitments.len();
+        assert_eq!(num_instances, 6);
+
+        // Make each witness commitment distinct.
+        for witness in commitments.witness_commitments.iter_mut() {
+            witness.w = KZGCommitment(G1Affine::rand(rng));
+        }
+
+        // Ensure in-range indices return the witness commitments of that instance.
+        for index in 0..num_instances {
+            assert_eq!(commitments.witness(index), Some(&commitments.witness_commitments[index]));
+        }
+        assert_eq!(commitments.iter_w().len(), num_instances);
+        assert!(commitments.iter_w().zip(&commitments.witness_commitments).all(|(w, witness)| w == &witness.w));
+
+        // Ensure out-of-range indices return `None`.
+        assert_eq!(commitments.witness(num_instances), None);
+        assert_eq!(commitments.witness(usize::MAX), None);
+        assert_eq!(rand_commitments(1, 1, false).witness(1), None);
+    }
+
+    #[test]
+    fn test_iter_batches() {
+        let rng = &mut TestRng::default();
+
+        // Construct the components of a proof over 3 circuits, with 1, 2, and 3 instances respectively.
+        let mut commitments = rand_commitments(1, 3, false);
+        commitments.witness_commitments = vec![WitnessCommitments { w: sample_commit() }; 6];
+        let template = Proof {
+            batch_sizes: vec![1, 2, 3],
+            commitments,
+            evaluations: rand_evaluations(rng, 3),
+            third_msg: ThirdMessage {
+                sums: vec![vec![rand_sums(rng)], vec![rand_sums(rng); 2], vec![rand_sums(rng); 3]],
+            },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 3] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+
+        // Construct the proof with the circuit IDs given out of order.
+        let batch_sizes = [(CircuitId([2u8; 32]), 3), (CircuitId([0u8; 32]), 1), (CircuitId([1u8; 32]), 2)];
+        let proof = Proof::new(
+            batch_sizes.into_iter().collect(),
+            template.commitments.clone(),
+            template.evaluations.clone(),
+            template.third_msg.clone(),
+            template.fourth_msg.clone(),
+            template.pc_proof.clone(),
+        )
+        .unwrap();
+
+        // Ensure the batches are yielded in the order of their circuit IDs.
+        assert_eq!(proof.iter_batches().len(), 3);
+        assert_eq!(proof.iter_batches().collect::<Vec<_>>(), [(0, 1), (1, 2), (2, 3)]);
+        assert_eq!(proof.iter_batches().map(|(_, batch_size)| batch_size).sum::<usize>(), proof.total_instances());
+
+        // Ensure batch sizes which disagree with the components are rejected.
+        let batch_sizes = [(CircuitId([0u8; 32]), 3), (CircuitId([1u8; 32]), 2), (CircuitId([2u8; 32]), 1)];
+        let result = Proof::new(
+            batch_sizes.into_iter().collect(),
+            template.commitments,
+            template.evaluations,
+            template.third_msg,
+            template.fourth_msg,
+            template.pc_proof,
+        );
+        assert!(matches!(result, Err(SNARKError::BatchSizeMismatch)));
+    }
+
+    #[test]
+    fn test_all_commitments() {
+        // Check commitments with a masking polynomial, for 3 circuits with 2 instances each.
+        let commitments = rand_commitments(2, 3, false);
+        let all_commitments = commitments.all_commitments();
+        assert_eq!(all_commitments.len(), 6 + 1 + 3 + 3 * 3 + 1);
+        // Ensure the commitments are in the order in which they are serialized.
+        let mut expected = Vec::new();
+        for commitment in &all_commitments {
+            commitment.serialize_compressed(&mut expected).unwrap();
+        }
+        let mut candidate = Vec::new();
+        commitments.serialize_with_mode(&mut candidate, Compress::Yes).unwrap();
+        // Account for the presence flag of the masking polynomial.
+        candidate.remove(6 * commitments.h_0.serialized_size(Compress::Yes));
+        assert_eq!(candidate, expected);
+
+        // Check commitments without a masking polynomial.
+        let commitments = rand_commitments(2, 3, true);
+        assert_eq!(commitments.all_commitments().len(), 6 + 3 + 3 * 3 + 1);
+    }
+
+    #[test]
+    fn test_commitments_serialization_order() {
+        // Sample distinct commitments, so that any reordering of the fields changes the serialized bytes.
+        let mut multiples =
+            (1u64..).map(|k| KZGCommitment((G1Affine::prime_subgroup_generator() * Fr::from(k)).to_affine()));
+        let mut next = || multiples.next().unwrap();
+        let commitments = Commitments::<Bls12_377> {
+            witness_commitments: (0..3).map(|_| WitnessCommitments { w: next() }).collect(),
+            mask_poly: Some(next()),
+            h_0: next(),
+            g_1: next(),
+            h_1: next(),
+            g_a_commitments: vec![next(), next()],
+            g_b_commitments: vec![next(), next()],
+            g_c_commitments: vec![next(), next()],
+            h_2: next(),
+        };
+        let batch_sizes = [2, 1];
+
+        for (compress, validate) in modes() {
+            // Lay out the expected bytes field by field.
+            let mut expected = Vec::new();
+            for witness_commitments in &commitments.witness_commitments {
+                witness_commitments.w.serialize_with_mode(&mut expected, compress).unwrap();
+            }
+            commitments.mask_poly.serialize_with_mode(&mut expected, compress).unwrap();
+            for commitment in [&commitments.h_0, &commitments.g_1, &commitments.h_1] {
+                commitment.serialize_with_mode(&mut expected, compress).unwrap();
+            }
+            for commitment in commitments.g_a_commitments.iter() {
+                commitment.serialize_with_mode(&mut expected, compress).unwrap();
+            }
+            for commitment in commitments.g_b_commitments.iter() {
+                commitment.serialize_with_mode(&mut expected, compress).unwrap();
+            }
+            for commitment in commitments.g_c_commitments.iter() {
+                commitment.serialize_with_mode(&mut expected, compress).unwrap();
+            }
+            commitments.h_2.serialize_with_mode(&mut expected, compress).unwrap();
+
+            // Ensure the commitments serialize to exactly the expected bytes.
+            let mut candidate = Vec::new();
+            commitments.serialize_with_mode(&mut candidate, compress).unwrap();
+            assert_eq!(candidate, expected);
+            assert_eq!(candidate.len(), commitments.serialized_size(compress));
+
+            // Ensure the pinned bytes deserialize back to the same commitments.
+            let recovered =
+                Commitments::deserialize_with_mode(&batch_sizes, &expected[..], compress, validate).unwrap();
+            assert_eq!(recovered, commitments);
+        }
+    }
+
+    #[test]
+    fn test_subset() {
+        let rng = &mut TestRng::default();
+
+        // Check a proof over 3 circuits, with 2 instances each.
+        let proof = Proof {
+            batch_sizes: vec![2; 3],
+            commitments: rand_commitments(2, 3, false),
+            evaluations: rand_evaluations(rng, 3),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 2]; 3] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 3] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        assert_eq!(proof.total_instances(), 6);
+
+        // Ensure the subset retains the selected instances, and drops the circuit without one.
+        let subset = proof.subset(&[0, 1, 4]).unwrap();
+        assert_eq!(subset.batch_sizes(), &[2, 1]);
+        assert_eq!(subset.total_instances(), 3);
+        assert!(subset.check_batch_sizes().is_ok());
+        assert_eq!(subset.commitments.witness_commitments.len(), 3);
+        assert_eq!(subset.evaluations.g_a_evals, [proof.evaluations.g_a_evals[0], proof.evaluations.g_a_evals[2]]);
+        assert_eq!(subset.third_msg.sums[1], [proof.third_msg.sums[2][0].clone()]);
+        assert_eq!(subset.fourth_msg.sums, [proof.fourth_msg.sums[0].clone(), proof.fourth_msg.sums[2].clone()]);
+
+        // Ensure the full subset is the original proof.
+        assert_eq!(proof.subset(&[0, 1, 2, 3, 4, 5]).unwrap(), proof);
+
+        // Ensure invalid indices are rejected.
+        assert!(proof.subset(&[]).is_err());
+        assert!(proof.subset(&[1, 0]).is_err());
+        assert!(proof.subset(&[1, 1]).is_err());
+        assert!(proof.subset(&[6]).is_err());
+    }
+
+    #[test]
+    fn test_evaluations_get_out_of_range() {
+        let rng = &mut TestRng::default();
+
+        let evaluations = rand_evaluations::<Fr>(rng, 2);
+        let circuit_id = CircuitId([7u8; 32]);
+
+        // Ensure an out-of-range circuit index returns `None` for every per-circuit polynomial.
+        for poly in ["g_a", "g_b", "g_c"] {
+            let label = witness_label(circuit_id, poly, 0);
+            assert!(evaluations.get(1, &label).is_some());
+            for circuit_index in [2, 3, usize::MAX] {
+                assert_eq!(evaluations.get(circuit_index, &label), None, "{label}");
+            }
+        }
+        // Ensure `g_1` is resolved for any circuit index, as it is shared across circuits.
+        assert_eq!(evaluations.get(usize::MAX, "g_1"), Some(evaluations.g_1_eval));
+    }
+
+    #[test]
+    fn test_evaluations_try_append() {
+        let rng = &mut TestRng::default();
+
+        let mut evaluations = rand_evaluations::<Fr>(rng, 2);
+        let mut other = rand_evaluations::<Fr>(rng, 1);
+
+        // Ensure evaluations with a different evaluation of g_1 are rejected, and left unchanged.
+        let expected = evaluations.clone();
+        assert!(evaluations.try_append(&other).is_err());
+        assert_eq!(evaluations, expected);
+
+        // Ensure the evaluations of the second circuit are appended.
+        other.g_1_eval = evaluations.g_1_eval;
+        evaluations.try_append(&other).unwrap();
+        assert_eq!(evaluations.to_field_elements().len(), 1 + 3 * 3);
+        assert_eq!(evaluations.evals_for_circuit(2), other.evals_for_circuit(0));
+        assert_eq!(evaluations.evals_for_circuit(0), expected.evals_for_circuit(0));
+    }
+
+    #[test]
+    fn test_proof_builder() {
+        let rng = &mut TestRng::default();
+
+        let template = Proof {
+            batch_sizes: vec![2; 3],
+            commitments: rand_commitments(2, 3, false),
+            evaluations: rand_evaluations(rng, 3),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 2]; 3] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 3] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        let batch_sizes = (0..3u8).map(|i| (CircuitId([i; 32]), 2)).collect::<BTreeMap<_, _>>();
+        let expected = Proof::new(
+            batch_sizes.clone(),
+            template.commitments.clone(),
+            template.evaluations.clone(),
+            template.third_msg.clone(),
+            template.fourth_msg.clone(),
+            template.pc_proof.clone(),
+        )
+        .unwrap();
+
+        // Ensure the builder produces the same proof, regardless of the order the components are set in.
+        let builder = ProofBuilder::new()
+            .with_pc_proof(template.pc_proof.clone())
+            .with_msg(template.third_msg.clone(), template.fourth_msg.clone())
+            .with_evaluations(template.evaluations.clone())
+            .with_commitments(template.commitments.clone());
+        let candidate = builder.clone().with_batch_sizes(batch_sizes).build().unwrap();
+        assert_eq!(candidate, expected);
+        assert_eq!(candidate, template);
+
+        // Ensure the builder fails if a component is missing.
+        assert!(builder.build().is_err());
+        assert!(ProofBuilder::<Bls12_377>::new().build().is_err());
+    }
+
+    #[test]
+    fn test_try_into_single() {
+        let rng = &mut TestRng::default();
+
+        // A single circuit with a single instance is returned unchanged.
+        let proof = Proof {
+            batch_sizes: vec![1],
+            commitments: rand_commitments(1, 1, false),
+            evaluations: rand_evaluations(rng, 1),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng)]] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng)] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        assert_eq!(proof.clone().try_into_single().unwrap(), proof);
+
+        // A single circuit with multiple instances is rejected.
+        let proof = Proof {
+            batch_sizes: vec![2],
+            commitments: rand_commitments(2, 1, false),
+            evaluations: rand_evaluations(rng, 1),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 2]] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng)] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        assert!(proof.try_into_single().is_err());
+        // Multiple circuits with a single instance each are rejected.
+        let proof = Proof {
+            batch_sizes: vec![1; 2],
+            commitments: rand_commitments(1, 2, false),
+            evaluations: rand_evaluations(rng, 2),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng)]; 2] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 2] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        assert!(proof.try_into_single().is_err());
+    }
+
+    #[test]
+    fn test_total_instances() {
+        let rng = &mut TestRng::default();
+
+        // Check a single circuit with a single instance.
+        let proof = Proof {
+            batch_sizes: vec![1],
+            commitments: rand_commitments(1, 1, false),
+            evaluations: rand_evaluations(rng, 1),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng)]] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng)] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        assert_eq!(proof.num_circuits(), 1);
+        assert_eq!(proof.total_instances(), 1);
+
+        // Check multiple circuits with multiple instances each.
+        let proof = Proof {
+            batch_sizes: vec![3; 4],
+            commitments: rand_commitments(3, 4, false),
+            evaluations: rand_evaluations(rng, 4),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 3]; 4] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 4] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        assert_eq!(proof.num_circuits(), 4);
+        assert_eq!(proof.total_instances(), 12);
+        assert_eq!(proof.batch_sizes(), &[3, 3, 3, 3]);
+
+        // Ensure batch sizes which overflow saturate, instead of panicking.
+        let proof = Proof { batch_sizes: vec![usize::MAX, 1], ..proof };
+        assert_eq!(proof.num_circuits(), 2);
+        assert_eq!(proof.total_instances(), usize::MAX);
+        assert!(proof.check_batch_sizes().is_err());
+    }
+
+    #[test]
+    fn test_byte_sizes() {
+        let rng = &mut TestRng::default();
+
+        let proof = Proof {
+            batch_sizes: vec![2; 3],
+            commitments: rand_commitments(2, 3, false),
+            evaluations: rand_evaluations(rng, 3),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 2]; 3] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 3] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        let (compressed, uncompressed) = proof.byte_sizes();
+
+        let mut buffer = Vec::new();
+        proof.serialize_compressed(&mut buffer).unwrap();
+        assert_eq!(buffer.len(), compressed);
+
+        let mut buffer = Vec::new();
+        proof.serialize_uncompressed(&mut buffer).unwrap();
+        assert_eq!(buffer.len(), uncompressed);
+        assert!(compressed < uncompressed);
+    }
+
+    #[test]
+    fn test_without_evaluations_size() {
+        let rng = &mut TestRng::default();
+
+        let proof = Proof {
+            batch_sizes: vec![2; 3],
+            commitments: rand_commitments(2, 3, false),
+            evaluations: rand_evaluations(rng, 3),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 2]; 3] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 3] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        for compress in [Compress::Yes, Compress::No] {
+            let evaluations_size = proof.evaluations.serialized_size(compress);
+            assert!(evaluations_size > 0);
+            assert_eq!(proof.without_evaluations_size(compress) + evaluations_size, proof.serialized_size(compress));
+        }
+    }
+
+    #[test]
+    fn test_serialized_size_without_mask_commitment() {
+        let rng = &mut TestRng::default();
+
+        let proof_with_mask = Proof {
+            batch_sizes: vec![2; 3],
+            commitments: rand_commitments(2, 3, false),
+            evaluations: rand_evaluations(rng, 3),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 2]; 3] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 3] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        // Ensure the proofs differ only in the masking polynomial.
+        let proof_without_mask = Proof { commitments: rand_commitments(2, 3, true), ..proof_with_mask.clone() };
+        for (compress, validate) in modes() {
+            // Ensure an absent masking polynomial only costs its presence flag.
+            let mask_size = proof_with_mask.commitments.mask_poly.as_ref().unwrap().serialized_size(compress);
+            assert_eq!(
+                proof_without_mask.serialized_size(compress) + mask_size,
+                proof_with_mask.serialized_size(compress)
+            );
+
+            // Ensure both forms round trip.
+            for proof in [&proof_with_mask, &proof_without_mask] {
+                let mut buffer = Vec::new();
+                proof.serialize_with_mode(&mut buffer, compress).unwrap();
+                assert_eq!(&Proof::deserialize_with_mode(&buffer[..], compress, validate).unwrap(), proof);
+            }
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        let rng = &mut TestRng::default();
+
+        let proof = Proof {
+            batch_sizes: vec![2; 3],
+            commitments: rand_commitments(2, 3, false),
+            evaluations: rand_evaluations(rng, 3),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 2]; 3] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 3] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        let summary = proof.summary();
+        assert!(summary.contains("circuits: 3"));
+        assert!(summary.contains("instances: 6"));
+        assert!(summary.contains("witness_commitments: 6"));
+        assert!(summary.contains(&format!("compressed_size: {}", proof.byte_sizes().0)));
+    }
+
+    #[test]
+    fn test_serde_json() {
+        let rng = &mut TestRng::default();
+
+        let expected = Proof {
+            batch_sizes: vec![2; 3],
+            commitments: rand_commitments(2, 3, false),
+            evaluations: rand_evaluations(rng, 3),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 2]; 3] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 3] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+
+        // Serialize
+        let expected_string = expected.to_string();
+        let candidate_string = serde_json::to_string(&expected).unwrap();
+        assert_eq!(expected_string, serde_json::Value::from_str(&candidate_string).unwrap().as_str().unwrap());
+
+        // Deserialize
+        assert_eq!(expected, Proof::from_str(&expected_string).unwrap());
+        assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+    }
+
+    #[test]
+    fn test_bincode() {
+        let rng = &mut TestRng::default();
+
+        let expected = Proof {
+            batch_sizes: vec![2; 3],
+            commitments: rand_commitments(2, 3, false),
+            evaluations: rand_evaluations(rng, 3),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 2]; 3] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 3] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        let candidate_bytes = bincode::serialize(&expected).unwrap();
+        assert_eq!(&expected_bytes[..], &candidate_bytes[8..]);
+
+        // Deserialize
+        assert_eq!(expected, Proof::read_le(&expected_bytes[..]).unwrap());
+        assert_eq!(expected, bincode::deserialize(&candidate_bytes[..]).unwrap());
+    }
+
+    #[test]
+    fn test_try_merge() {
+        let rng = &mut TestRng::default();
+
+        // Sample two single-circuit proofs, which share their batch-wide components.
+        let first = Proof {
+            batch_sizes: vec![2],
+            commitments: rand_commitments(2, 1, false),
+            evaluations: rand_evaluations(rng, 1),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 2]] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng)] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        let mut second = Proof {
+            batch_sizes: vec![3],
+            commitments: rand_commitments(3, 1, false),
+            evaluations: rand_evaluations(rng, 1),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 3]] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng)] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        second.evaluations.g_1_eval = first.evaluations.g_1_eval;
+        second.pc_proof = first.pc_proof.clone();
+
+        // Check the shape of the merged proof.
+        let merged = first.clone().try_merge(second.clone()).unwrap();
+        assert_eq!(merged.batch_sizes(), &[2, 3]);
+        assert_eq!(merged.num_circuits(), 2);
+        assert_eq!(merged.total_instances(), 5);
+        assert_eq!(merged.commitments.witness_commitments.len(), 5);
+        assert_eq!(merged.commitments.g_a_commitments.len(), 2);
+        let expected_g_a_evals = [first.evaluations.g_a_evals.clone(), second.evaluations.g_a_evals.clone()].concat();
+        assert_eq!(merged.evaluations.g_a_evals, expected_g_a_evals);
+        assert_eq!(merged.third_msg.sums.len(), 2);
+        assert_eq!(merged.fourth_msg.sums.len(), 2);
+        assert!(merged.check_batch_sizes().is_ok());
+
+        // Check that proofs with different shared components are rejected.
+        let mut candidate = second.clone();
+        candidate.commitments.mask_poly = None;
+        assert!(first.clone().try_merge(candidate).is_err());
+        let mut candidate = second.clone();
+        candidate.evaluations.g_1_eval = Fr::rand(rng);
+        assert!(first.clone().try_merge(candidate).is_err());
+        let mut candidate = second;
+        candidate.pc_proof = sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) };
+        assert!(first.try_merge(candidate).is_err());
+    }
+
+    #[test]
+    fn test_mask_commitment() {
+        let rng = &mut TestRng::default();
+
+        // Check a proof with a masking polynomial.
+        let proof = Proof {
+            batch_sizes: vec![1],
+            commitments: rand_commitments(1, 1, false),
+            evaluations: rand_evaluations(rng, 1),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng)]] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng)] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        assert_eq!(proof.mask_commitment(), proof.commitments.mask_poly.as_ref());
+        assert!(proof.mask_commitment().is_some());
+
+        // Check a proof without a masking polynomial.
+        let proof = Proof {
+            batch_sizes: vec![1],
+            commitments: rand_commitments(1, 1, true),
+            evaluations: rand_evaluations(rng, 1),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng)]] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng)] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, true)]) },
+        };
+        assert!(proof.mask_commitment().is_none());
+    }
+
+    #[test]
+    fn test_check_witness_commitments() {
+        let rng = &mut TestRng::default();
+
+        // Check a valid proof.
+        let proof = Proof {
+            batch_sizes: vec![2; 3],
+            commitments: rand_commitments(2, 3, false),
+            evaluations: rand_evaluations(rng, 3),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 2]; 3] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 3] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        assert!(proof.check().is_ok());
+
+        // Check a proof with a missing witness commitment.
+        let mut candidate = proof.clone();
+        candidate.commitments.witness_commitments.pop();
+        assert!(candidate.check().is_err());
+
+        // Check a zero-knowledge proof with a zeroed witness commitment.
+        let mut candidate = proof;
+        candidate.commitments.witness_commitments[1].w = sonic_pc::Commitment::empty();
+        assert!(candidate.check().is_err());
+    }
+
+    #[test]
+    fn test_shared_commitments() {
+        let rng = &mut TestRng::default();
+
+        let proof = Proof {
+            batch_sizes: vec![2; 3],
+            commitments: rand_commitments(2, 3, false),
+            evaluations: rand_evaluations(rng, 3),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 2]; 3] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 3] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        let (g_1, h_1, h_2) = proof.shared_commitments();
+        assert!(std::ptr::eq(g_1, &proof.commitments.g_1));
+        assert!(std::ptr::eq(h_1, &proof.commitments.h_1));
+        assert!(std::ptr::eq(h_2, &proof.commitments.h_2));
+    }
+
+    #[test]
+    fn test_assert_canonical() {
+        let rng = &mut TestRng::default();
+
+        // Check a canonically-encoded proof.
+        let proof = Proof {
+            batch_sizes: vec![2; 3],
+            commitments: rand_commitments(2, 3, false),
+            evaluations: rand_evaluations(rng, 3),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 2]; 3] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 3] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        let bytes = proof.to_bytes_le().unwrap();
+        let candidate = Proof::<Bls12_377>::read_le(&bytes[..]).unwrap();
+        assert!(candidate.assert_canonical(&bytes).is_ok());
+
+        // Check a proof with trailing bytes, which decodes to the same proof.
+        let mut bytes_with_trailing = bytes.clone();
+        bytes_with_trailing.push(0u8);
+        let candidate = Proof::<Bls12_377>::read_le(&bytes_with_trailing[..]).unwrap();
+        assert_eq!(candidate, proof);
+        assert!(candidate.assert_canonical(&bytes_with_trailing).is_err());
+
+        // Check a proof against the uncompressed encoding.
+        let mut uncompressed = Vec::new();
+        proof.serialize_uncompressed(&mut uncompressed).unwrap();
+        assert!(proof.assert_canonical(&uncompressed).is_err());
+    }
+
+    #[test]
+    fn test_deserializing_proof_exceeds_max_batch_size() {
+        for batch_sizes in [
+            vec![1u64 << 40],
+            vec![MAX_BATCH_SIZE as u64 + 1],
+            vec![MAX_BATCH_SIZE as u64, 1],
+            vec![u64::MAX, u64::MAX],
+        ] {
+            // Craft a proof header declaring the batch sizes.
+            let mut serialized = Vec::new();
+            CanonicalSerialize::serialize_with_mode(&batch_sizes, &mut serialized, Compress::Yes).unwrap();
+            // Append a valid-looking commitment, to ensure deserialization fails on the batch size.
+            sample_commit().serialize_compressed(&mut serialized).unwrap();
+
+            for (compress, validate) in modes() {
+                assert!(Proof::<Bls12_377>::deserialize_with_mode(&serialized[..], compress, validate).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_deserializing_proof_with_corrupted_batch_sizes() {
+        let rng = &mut TestRng::default();
+
+        let proof = Proof {
+            batch_sizes: vec![2; 3],
+            commitments: rand_commitments(2, 3, false),
+            evaluations: rand_evaluations(rng, 3),
+            third_msg: ThirdMessage { sums: vec![vec![rand_sums(rng); 2]; 3] },
+            fourth_msg: FourthMessage { sums: vec![rand_sums(rng); 3] },
+            pc_proof: sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, false)]) },
+        };
+        for (compress, validate) in modes() {
+            let mut serialized = Vec::new();
+            proof.serialize_with_mode(&mut serialized, compress).unwrap();
+            // Ensure the valid proof deserializes.
+            assert_eq!(Proof::deserialize_with_mode(&serialized[..], compress, validate).unwrap(), proof);
+
+            // Corrupt the batch size of the first circuit, which follows the 8-byte length prefix.
+            for corrupted_size in [0u64, 3] {
+                let mut corrupted = serialized.clone();
+                corrupted[8..16].copy_from_slice(&corrupted_size.to_le_bytes());
+                assert!(Proof::<Bls12_377>::deserialize_with_mode(&corrupted[..], compress, validate).is_err());
+            }
+
+            // Corrupt the number of circuits.
+            let mut corrupted = serialized.clone();
+            corrupted[0..8].copy_from_slice(&0u64.to_le_bytes());
+            assert!(Proof::<Bls12_377>::deserialize_with_mode(&corrupted[..], compress, validate).is_err());
+        }
+    }
 }
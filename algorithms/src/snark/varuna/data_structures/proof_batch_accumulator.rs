@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    polycommit::sonic_pc::{BatchCheckElems, SonicKZG10},
+    snark::varuna::{CircuitVerifyingKey, Proof, SNARKMode, VarunaSNARK},
+    srs::UniversalVerifier,
+    AlgebraicSponge,
+    SNARKError,
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::rand::Uniform;
+
+use anyhow::{bail, Result};
+use rand::{CryptoRng, Rng};
+use std::collections::BTreeMap;
+
+/// An accumulator of Varuna proofs, along with their verifying keys and public inputs,
+/// which verifies all of the proofs with a single product of pairings.
+#[derive(Clone, Debug)]
+pub struct ProofBatchAccumulator<E: PairingEngine> {
+    /// The verifying keys and public inputs of each proof, along with the proof.
+    proofs: Vec<(BTreeMap<CircuitVerifyingKey<E>, Vec<Vec<E::Fr>>>, Proof<E>)>,
+}
+
+impl<E: PairingEngine> Default for ProofBatchAccumulator<E> {
+    /// Initializes an empty accumulator.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: PairingEngine> ProofBatchAccumulator<E> {
+    /// Initializes an empty accumulator.
+    pub fn new() -> Self {
+        Self { proofs: Vec::new() }
+    }
+
+    /// Initializes an empty accumulator, with capacity for the given number of proofs.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { proofs: Vec::with_capacity(capacity) }
+    }
+
+    /// Adds a proof, along with the public inputs for each of its verifying keys.
+    pub fn push(&mut self, keys_to_inputs: BTreeMap<CircuitVerifyingKey<E>, Vec<Vec<E::Fr>>>, proof: Proof<E>) {
+        self.proofs.push((keys_to_inputs, proof));
+    }
+
+    /// Returns the number of accumulated proofs.
+    pub fn len(&self) -> usize {
+        self.proofs.len()
+    }
+
+    /// Returns `true` if there are no accumulated proofs.
+    pub fn is_empty(&self) -> bool {
+        self.proofs.is_empty()
+    }
+
+    /// Returns `true` if all of the accumulated proofs are valid.
+    ///
+    /// Each proof is verified up to the final pairing check of its polynomial commitments.
+    /// The pairing checks are then combined with a random linear combination, and checked at once.
+    pub fn verify_all<FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode, R: Rng + CryptoRng>(
+        &self,
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        rng: &mut R,
+    ) -> Result<bool>
+    where
+        E::Fr: PrimeField,
+        E::Fq: PrimeField,
+    {
+        let mut accumulated: Option<BatchCheckElems<E>> = None;
+        for (keys_to_inputs, proof) in self.proofs.iter() {
+            let keys_to_inputs =
+                keys_to_inputs.iter().map(|(vk, inputs)| (vk, inputs.as_slice())).collect::<BTreeMap<_, _>>();
+            let elems = match VarunaSNARK::<E, FS, SM>::accumulate_verification(
+                universal_verifier,
+                fs_parameters,
+                &keys_to_inputs,
+                proof,
+            )? {
+                Some(elems) => elems,
+                None => return Ok(false),
+            };
+            match accumulated.as_mut() {
+                Some(accumulated) => accumulated.accumulate(elems, E::Fr::rand(rng)),
+                None => accumulated = Some(elems),
+            }
+        }
+        match accumulated {
+            Some(elems) => SonicKZG10::<E, FS>::check_elems(universal_verifier, elems),
+            None => bail!(SNARKError::EmptyBatch),
+        }
+    }
+}
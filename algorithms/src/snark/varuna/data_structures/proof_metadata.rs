@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    snark::varuna::{CircuitId, Proof},
+    SNARKError,
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_utilities::{
+    error,
+    io::{self, Read, Write},
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
+
+use std::collections::BTreeMap;
+
+/// The circuit IDs proven in a Varuna proof, along with the number of instances proven for each of them.
+///
+/// A `Proof` only stores its batch sizes in the order of their circuit IDs, so this metadata
+/// allows an external verifier to map the per-circuit components of the proof back to named circuits.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ProofMetadata {
+    /// The number of instances proven for each circuit.
+    batch_sizes: BTreeMap<CircuitId, usize>,
+}
+
+impl ProofMetadata {
+    /// Initializes the metadata from the batch sizes used to construct a proof.
+    pub fn new(batch_sizes: BTreeMap<CircuitId, usize>) -> Self {
+        Self { batch_sizes }
+    }
+
+    /// Returns the number of instances proven for each circuit.
+    pub const fn batch_sizes(&self) -> &BTreeMap<CircuitId, usize> {
+        &self.batch_sizes
+    }
+
+    /// Returns the circuit IDs, in the order their components are laid out in the proof.
+    pub fn circuit_ids(&self) -> impl '_ + ExactSizeIterator<Item = &CircuitId> {
+        self.batch_sizes.keys()
+    }
+
+    /// Returns the index of the given circuit within the proof, if it was proven.
+    pub fn circuit_index(&self, circuit_id: &CircuitId) -> Option<usize> {
+        self.batch_sizes.keys().position(|id| id == circuit_id)
+    }
+
+    /// Ensures the metadata describes the given proof.
+    pub fn check<E: PairingEngine>(&self, proof: &Proof<E>) -> Result<(), SNARKError> {
+        match self.batch_sizes.values().eq(proof.batch_sizes().iter()) {
+            true => Ok(()),
+            false => Err(SNARKError::BatchSizeMismatch),
+        }
+    }
+}
+
+impl ToBytes for ProofMetadata {
+    fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
+        Self::serialize_compressed(self, &mut w).map_err(|_| error("could not serialize ProofMetadata"))
+    }
+}
+
+impl FromBytes for ProofMetadata {
+    fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(&mut r).map_err(|_| error("could not deserialize ProofMetadata"))
+    }
+}
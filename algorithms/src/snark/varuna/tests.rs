@@ -20,6 +20,7 @@ mod varuna {
             test_circuit::TestCircuit,
             AHPForR1CS,
             CircuitVerifyingKey,
+            ProofBatchAccumulator,
             VarunaHidingMode,
             VarunaNonHidingMode,
             VarunaSNARK,
@@ -155,6 +156,114 @@ mod varuna {
                     }
                 }
 
+                pub(crate) fn test_proof_batch_accumulator(num_constraints: usize, num_variables: usize) {
+                    let rng = &mut TestRng::default();
+
+                    let max_degree = AHPForR1CS::<Fr, $snark_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $snark_inst::universal_setup(max_degree).unwrap();
+                    let universal_prover = &universal_srs.to_universal_prover().unwrap();
+                    let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+                    let fs_parameters = FS::sample_parameters();
+
+                    let mut accumulator = ProofBatchAccumulator::with_capacity(3);
+                    let mut invalid_accumulator = ProofBatchAccumulator::with_capacity(3);
+                    for i in 0..3 {
+                        let mul_depth = 1 + i;
+                        let (circ, public_inputs) = TestCircuit::gen_rand(mul_depth, num_constraints + i, num_variables + i, rng);
+                        let (index_pk, index_vk) = $snark_inst::circuit_setup(&universal_srs, &circ).unwrap();
+                        let proof = $snark_inst::prove(universal_prover, &fs_parameters, &index_pk, &circ, rng).unwrap();
+
+                        // Corrupt the public inputs of the last proof in the invalid batch.
+                        let mut invalid_inputs = public_inputs.clone();
+                        if i == 2 {
+                            invalid_inputs[public_inputs.len() - 1] = Fr::rand(rng);
+                        }
+                        accumulator.push(BTreeMap::from([(index_vk.clone(), vec![public_inputs])]), proof.clone());
+                        invalid_accumulator.push(BTreeMap::from([(index_vk, vec![invalid_inputs])]), proof);
+                    }
+                    assert_eq!(accumulator.len(), 3);
+
+                    assert!(accumulator.verify_all::<FS, $snark_mode, _>(universal_verifier, &fs_parameters, rng).unwrap());
+                    eprintln!("\nShould not verify (i.e. verifier messages should print below):");
+                    assert!(!invalid_accumulator.verify_all::<FS, $snark_mode, _>(universal_verifier, &fs_parameters, rng).unwrap());
+                }
+
+                pub(crate) fn test_verify_many_instances(num_constraints: usize, num_variables: usize) {
+                    let rng = &mut TestRng::default();
+
+                    let max_degree = AHPForR1CS::<Fr, $snark_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $snark_inst::universal_setup(max_degree).unwrap();
+                    let universal_prover = &universal_srs.to_universal_prover().unwrap();
+                    let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+                    let fs_parameters = FS::sample_parameters();
+
+                    // Prove 16 instances of the same circuit.
+                    let mul_depth = 2;
+                    let (circuits, public_inputs): (Vec<_>, Vec<_>) = (0..16)
+                        .map(|_| TestCircuit::gen_rand(mul_depth, num_constraints, num_variables, rng))
+                        .unzip();
+                    let (index_pk, index_vk) = $snark_inst::circuit_setup(&universal_srs, &circuits[0]).unwrap();
+                    let pks_to_constraints = BTreeMap::from([(&index_pk, circuits.as_slice())]);
+                    let proof = $snark_inst::prove_batch(universal_prover, &fs_parameters, &pks_to_constraints, rng).unwrap();
+
+                    // Ensure the sequential and parallel verifiers both accept the proof.
+                    let vks_to_inputs = BTreeMap::from([(&index_vk, public_inputs.as_slice())]);
+                    assert!($snark_inst::verify_batch(universal_verifier, &fs_parameters, &vks_to_inputs, &proof).unwrap());
+                    assert!($snark_inst::verify_batch_parallel(universal_verifier, &fs_parameters, &vks_to_inputs, &proof).unwrap());
+
+                    // Ensure corrupting a single instance causes both verifiers to reject the proof.
+                    eprintln!("\nShould not verify (i.e. verifier messages should print below):");
+                    let mut fake_inputs = public_inputs.clone();
+                    let last = fake_inputs[7].len() - 1;
+                    fake_inputs[7][last] = Fr::rand(rng);
+                    let vks_to_inputs = BTreeMap::from([(&index_vk, fake_inputs.as_slice())]);
+                    assert!(!$snark_inst::verify_batch(universal_verifier, &fs_parameters, &vks_to_inputs, &proof).unwrap());
+                    assert!(!$snark_inst::verify_batch_parallel(universal_verifier, &fs_parameters, &vks_to_inputs, &proof).unwrap());
+
+                    // Ensure both verifiers reject a public input that does not start with one, with an error.
+                    let mut malformed_inputs = public_inputs.clone();
+                    malformed_inputs[3][0] = Fr::from(2u64);
+                    let vks_to_inputs = BTreeMap::from([(&index_vk, malformed_inputs.as_slice())]);
+                    assert!($snark_inst::verify_batch(universal_verifier, &fs_parameters, &vks_to_inputs, &proof).is_err());
+                    assert!($snark_inst::verify_batch_parallel(universal_verifier, &fs_parameters, &vks_to_inputs, &proof).is_err());
+                }
+
+                pub(crate) fn test_verify_many(num_constraints: usize, num_variables: usize) {
+                    let rng = &mut TestRng::default();
+
+                    let max_degree = AHPForR1CS::<Fr, $snark_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $snark_inst::universal_setup(max_degree).unwrap();
+                    let universal_prover = &universal_srs.to_universal_prover().unwrap();
+                    let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+                    let fs_parameters = FS::sample_parameters();
+
+                    // Prove two distinct circuits.
+                    let mut vks = Vec::with_capacity(2);
+                    let mut proofs = Vec::with_capacity(2);
+                    let mut inputs = Vec::with_capacity(2);
+                    for i in 0..2 {
+                        let mul_depth = 1 + i;
+                        let (circ, public_inputs) = TestCircuit::gen_rand(mul_depth, num_constraints + i, num_variables + i, rng);
+                        let (index_pk, index_vk) = $snark_inst::circuit_setup(&universal_srs, &circ).unwrap();
+                        proofs.push($snark_inst::prove(universal_prover, &fs_parameters, &index_pk, &circ, rng).unwrap());
+                        vks.push(index_vk);
+                        inputs.push(public_inputs);
+                    }
+                    assert_ne!(vks[0], vks[1]);
+                    assert!($snark_inst::verify_many(universal_verifier, &fs_parameters, &vks, &proofs, &inputs).unwrap());
+
+                    // Ensure a single invalid proof causes the combined result to be false.
+                    eprintln!("\nShould not verify (i.e. verifier messages should print below):");
+                    let mut fake_inputs = inputs.clone();
+                    let last = fake_inputs[1].len() - 1;
+                    fake_inputs[1][last] = Fr::rand(rng);
+                    assert!(!$snark_inst::verify_many(universal_verifier, &fs_parameters, &vks, &proofs, &fake_inputs).unwrap());
+
+                    // Ensure mismatched lengths are rejected.
+                    assert!($snark_inst::verify_many(universal_verifier, &fs_parameters, &vks, &proofs[..1], &inputs).is_err());
+                    assert!($snark_inst::verify_many(universal_verifier, &fs_parameters, &vks[..0], &proofs[..0], &inputs[..0]).is_err());
+                }
+
                 pub(crate) fn test_serde_json(num_constraints: usize, num_variables: usize) {
                     use std::str::FromStr;
 
@@ -297,6 +406,33 @@ mod varuna {
         SonicPCTest::test_bincode(num_constraints, num_variables);
         SonicPCPoswTest::test_bincode(num_constraints, num_variables);
     }
+
+    #[test]
+    fn prove_and_verify_with_proof_batch_accumulator() {
+        let num_constraints = 25;
+        let num_variables = 25;
+
+        SonicPCTest::test_proof_batch_accumulator(num_constraints, num_variables);
+        SonicPCPoswTest::test_proof_batch_accumulator(num_constraints, num_variables);
+    }
+
+    #[test]
+    fn prove_and_verify_many() {
+        let num_constraints = 25;
+        let num_variables = 25;
+
+        SonicPCTest::test_verify_many(num_constraints, num_variables);
+        SonicPCPoswTest::test_verify_many(num_constraints, num_variables);
+    }
+
+    #[test]
+    fn prove_and_verify_with_many_instances() {
+        let num_constraints = 25;
+        let num_variables = 25;
+
+        SonicPCTest::test_verify_many_instances(num_constraints, num_variables);
+        SonicPCPoswTest::test_verify_many_instances(num_constraints, num_variables);
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
@@ -16,6 +16,7 @@ use super::Certificate;
 use crate::{
     fft::EvaluationDomain,
     polycommit::sonic_pc::{
+        BatchCheckElems,
         Commitment,
         CommitterUnionKey,
         Evaluations,
@@ -44,7 +45,7 @@ use crate::{
 use rand::RngCore;
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
-use snarkvm_utilities::{to_bytes_le, ToBytes};
+use snarkvm_utilities::{cfg_iter, to_bytes_le, ToBytes};
 
 use anyhow::{anyhow, bail, ensure, Result};
 use core::marker::PhantomData;
@@ -53,6 +54,8 @@ use rand::{CryptoRng, Rng};
 use std::{borrow::Borrow, collections::BTreeMap, ops::Deref, sync::Arc};
 
 use crate::srs::UniversalProver;
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
 #[cfg(not(feature = "std"))]
 use snarkvm_utilities::println;
 
@@ -589,7 +592,7 @@ where
             }
         }
 
-        let evaluations = proof::Evaluations::from_map(&evaluations, batch_sizes.clone());
+        let evaluations = proof::Evaluations::from_map(&evaluations, batch_sizes.clone())?;
         end_timer!(eval_time);
 
         sponge.absorb_nonnative_field_elements(evaluations.to_field_elements());
@@ -612,7 +615,6 @@ where
             prover_fourth_message,
             pc_proof,
         )?;
-        proof.check_batch_sizes()?;
         ensure!(proof.pc_proof.is_hiding() == SM::ZK);
 
         end_timer!(prover_time);
@@ -628,6 +630,93 @@ where
         keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
         proof: &Self::Proof,
     ) -> Result<bool> {
+        Self::verify_batch_internal(universal_verifier, fs_parameters, keys_to_inputs, proof, false)
+    }
+}
+
+impl<E: PairingEngine, FS, SM> VarunaSNARK<E, FS, SM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    SM: SNARKMode,
+{
+    /// Verifies the given proof as `verify_batch` does, but parses and pads the public inputs of the instances
+    /// concurrently. This is opt-in, as it only pays off for proofs with many instances per circuit.
+    /// The accept or reject decision is identical to that of `verify_batch`.
+    pub fn verify_batch_parallel<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&CircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+    ) -> Result<bool> {
+        Self::verify_batch_internal(universal_verifier, fs_parameters, keys_to_inputs, proof, true)
+    }
+
+    /// Runs the verifier on the given proof, up to the final pairing check of the polynomial commitments.
+    /// Returns the accumulated elements of that pairing check, or `None` if the proof has an incorrect hiding mode.
+    pub fn accumulate_verification<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&CircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+    ) -> Result<Option<BatchCheckElems<E>>> {
+        let accumulated =
+            Self::accumulate_verification_internal(universal_verifier, fs_parameters, keys_to_inputs, proof, false)?;
+        Ok(accumulated.map(|(elems, _)| elems))
+    }
+
+    /// Runs the verifier on the given proof, including the final pairing check of the polynomial commitments.
+    /// If `parallel` is set, the public inputs of the instances are processed concurrently.
+    fn verify_batch_internal<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&CircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+        parallel: bool,
+    ) -> Result<bool> {
+        let verifier_time = start_timer!(|| format!(
+            "Varuna::Verify with batch sizes: {:?}",
+            keys_to_inputs.iter().map(|(vk, inputs)| (vk.id, inputs.len())).collect::<BTreeMap<_, _>>()
+        ));
+
+        let (elems, gamma) = match Self::accumulate_verification_internal(
+            universal_verifier,
+            fs_parameters,
+            keys_to_inputs,
+            proof,
+            parallel,
+        )? {
+            Some(accumulated) => accumulated,
+            None => return Ok(false),
+        };
+
+        let pc_time = start_timer!(|| "Checking linear combinations with PC");
+        let evaluations_are_correct = SonicKZG10::<E, FS>::check_elems(universal_verifier, elems)?;
+        end_timer!(pc_time);
+
+        if !evaluations_are_correct && cfg!(debug_assertions) {
+            eprintln!("SonicKZG10::Check failed using final challenge: {:?}", gamma);
+        }
+
+        end_timer!(verifier_time, || format!(
+            " SonicKZG10::Check for AHP Verifier linear equations: {}",
+            evaluations_are_correct
+        ));
+        Ok(evaluations_are_correct)
+    }
+
+    /// Runs the verifier on the given proof, up to the final pairing check of the polynomial commitments.
+    /// Returns the accumulated elements of that pairing check along with the final challenge,
+    /// or `None` if the proof has an incorrect hiding mode.
+    /// If `parallel` is set, the public inputs of the instances are processed concurrently.
+    fn accumulate_verification_internal<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&CircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+        parallel: bool,
+    ) -> Result<Option<(BatchCheckElems<E>, Option<E::Fr>)>> {
         if keys_to_inputs.is_empty() {
             bail!(SNARKError::EmptyBatch);
         }
@@ -668,35 +757,28 @@ where
                 .ok_or(anyhow!("Failed to create EvaluationDomain from num_public_inputs"))?;
             input_domains.insert(vk.id, input_domain);
 
-            let input_fields = public_inputs_i
-                .iter()
-                .map(|input| {
-                    let input = input.borrow().to_field_elements()?;
-                    ensure!(input.len() > 0);
-                    ensure!(input[0] == E::Fr::one());
-                    if input.len() > input_domain.size() {
-                        bail!(SNARKError::PublicInputSizeMismatch);
-                    }
-                    Ok(input)
-                })
-                .collect::<Result<Vec<_>, _>>()?;
-
-            let (padded_public_inputs_i, parsed_public_inputs_i): (Vec<_>, Vec<_>) = {
-                input_fields
-                    .iter()
-                    .map(|input| {
-                        let input_len = input.len().max(input_domain.size());
-                        let mut new_input = Vec::with_capacity(input_len);
-                        new_input.extend_from_slice(input);
-                        new_input.resize(input_len, E::Fr::zero());
-                        if cfg!(debug_assertions) {
-                            println!("Number of padded public variables: {}", new_input.len());
-                        }
-                        let unformatted = prover::ConstraintSystem::unformat_public_input(&new_input);
-                        (new_input, unformatted)
-                    })
-                    .unzip()
+            let (padded_public_inputs_i, parsed_public_inputs_i): (Vec<_>, Vec<_>) = match parallel {
+                true => {
+                    // Borrow the public inputs of each instance, so that they may be processed concurrently.
+                    let public_inputs_i = public_inputs_i.iter().map(|input| input.borrow()).collect::<Vec<_>>();
+                    let input_fields = cfg_iter!(public_inputs_i)
+                        .map(|input| Self::parse_public_input(input, &input_domain))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    cfg_iter!(input_fields).map(|input| Self::pad_public_input(input, &input_domain)).unzip()
+                }
+                false => {
+                    let input_fields = public_inputs_i
+                        .iter()
+                        .map(|input| Self::parse_public_input(input.borrow(), &input_domain))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    input_fields.iter().map(|input| Self::pad_public_input(input, &input_domain)).unzip()
+                }
             };
+            if cfg!(debug_assertions) {
+                for padded_input in &padded_public_inputs_i {
+                    println!("Number of padded public variables: {}", padded_input.len());
+                }
+            }
             let circuit_id = vk.id;
             public_inputs.insert(circuit_id, parsed_public_inputs_i);
             padded_public_vec.push(padded_public_inputs_i);
@@ -723,11 +805,9 @@ where
                 "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
                 proof.pc_proof.is_hiding()
             );
-            return Ok(false);
+            return Ok(None);
         }
 
-        let verifier_time = start_timer!(|| format!("Varuna::Verify with batch sizes: {:?}", batch_sizes));
-
         let first_round_info = AHPForR1CS::<E::Fr, SM>::first_round_polynomial_info(batch_sizes.iter());
 
         let mut first_comms_consumed = 0;
@@ -896,8 +976,8 @@ where
         )?;
         end_timer!(lc_time);
 
-        let pc_time = start_timer!(|| "Checking linear combinations with PC");
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
+        let pc_time = start_timer!(|| "Accumulating linear combinations with PC");
+        let elems = SonicKZG10::<E, FS>::accumulate_combinations(
             universal_verifier,
             lc_s.values(),
             &commitments,
@@ -908,15 +988,27 @@ where
         )?;
         end_timer!(pc_time);
 
-        if !evaluations_are_correct {
-            #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::Check failed using final challenge: {:?}", verifier_state.gamma);
+        Ok(Some((elems, verifier_state.gamma)))
+    }
+
+    /// Returns the field elements of the given public input, ensuring it is well-formed for the input domain.
+    fn parse_public_input(input: &[E::Fr], input_domain: &EvaluationDomain<E::Fr>) -> Result<Vec<E::Fr>> {
+        let input = input.to_field_elements()?;
+        ensure!(input.len() > 0);
+        ensure!(input[0] == E::Fr::one());
+        if input.len() > input_domain.size() {
+            bail!(SNARKError::PublicInputSizeMismatch);
         }
+        Ok(input)
+    }
 
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+    /// Returns the given public input padded to the size of the input domain, along with its unformatted form.
+    fn pad_public_input(input: &[E::Fr], input_domain: &EvaluationDomain<E::Fr>) -> (Vec<E::Fr>, Vec<E::Fr>) {
+        let input_len = input.len().max(input_domain.size());
+        let mut new_input = Vec::with_capacity(input_len);
+        new_input.extend_from_slice(input);
+        new_input.resize(input_len, E::Fr::zero());
+        let unformatted = prover::ConstraintSystem::unformat_public_input(&new_input);
+        (new_input, unformatted)
     }
 }
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{r1cs::ConstraintSynthesizer, AlgebraicSponge};
+use crate::{r1cs::ConstraintSynthesizer, AlgebraicSponge, SNARKError};
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, FromBytes, ToBytes};
 
@@ -114,4 +114,28 @@ pub trait SNARK {
         keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
         proof: &Self::Proof,
     ) -> Result<bool>;
+
+    /// Verifies each proof against its verifying key and input, where each proof is for a single instance.
+    /// Returns `true` only if every proof is valid, stopping at the first invalid proof.
+    fn verify_many<B: Borrow<Self::VerifierInput>>(
+        universal_verifier: &Self::UniversalVerifier,
+        fs_parameters: &Self::FSParameters,
+        verifying_keys: &[Self::VerifyingKey],
+        proofs: &[Self::Proof],
+        inputs: &[B],
+    ) -> Result<bool, SNARKError> {
+        if verifying_keys.is_empty() {
+            return Err(SNARKError::EmptyBatch);
+        }
+        if verifying_keys.len() != proofs.len() || verifying_keys.len() != inputs.len() {
+            return Err(SNARKError::BatchSizeMismatch);
+        }
+        for (i, (verifying_key, proof)) in verifying_keys.iter().zip(proofs).enumerate() {
+            let keys_to_inputs = BTreeMap::from([(verifying_key, &inputs[i..=i])]);
+            if !Self::verify_batch(universal_verifier, fs_parameters, &keys_to_inputs, proof)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
 }
@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Enforces that the given number of future inputs does not exceed `max_inputs`.
+pub fn enforce_future_arity<A: Aleo>(num_inputs: &U8<A>, max_inputs: u8) {
+    A::assert(num_inputs.is_less_than_or_equal(&U8::constant(console::U8::new(max_inputs))));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_circuit_types::environment::{assert_scope, assert_scope_fails};
+
+    fn check_enforce_future_arity(
+        num_inputs: u8,
+        mode: Mode,
+        is_satisfied: bool,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let candidate = U8::<Circuit>::new(mode, console::U8::new(num_inputs));
+        Circuit::scope(format!("{mode} {num_inputs}"), || {
+            enforce_future_arity(&candidate, 4);
+            match is_satisfied {
+                true => assert_scope!(num_constants, num_public, num_private, num_constraints),
+                false => assert_scope_fails!(num_constants, num_public, num_private, num_constraints),
+            }
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_enforce_future_arity_constant() {
+        // Ensure an in-range arity is enforced without any constraints.
+        for num_inputs in [0u8, 3, 4] {
+            check_enforce_future_arity(num_inputs, Mode::Constant, true, 9, 0, 0, 0);
+        }
+    }
+
+    #[test]
+    fn test_enforce_future_arity() {
+        for mode in [Mode::Public, Mode::Private] {
+            // Ensure the constraints are satisfied for an in-range arity.
+            for num_inputs in [0u8, 3, 4] {
+                check_enforce_future_arity(num_inputs, mode, true, 16, 0, 9, 11);
+            }
+            // Ensure the constraints are unsatisfied for an over-limit arity.
+            for num_inputs in [5u8, u8::MAX] {
+                check_enforce_future_arity(num_inputs, mode, false, 16, 0, 9, 11);
+            }
+        }
+    }
+}
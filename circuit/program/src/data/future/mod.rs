@@ -15,6 +15,9 @@
 mod argument;
 pub use argument::Argument;
 
+mod arity;
+pub use arity::enforce_future_arity;
+
 mod equal;
 mod find;
 mod to_bits;
@@ -22,7 +25,7 @@ mod to_fields;
 
 use crate::{Access, Identifier, Plaintext, ProgramID, Value};
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, U16};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, U16, U8};
 
 /// A future.
 #[derive(Clone)]
@@ -18,6 +18,9 @@ impl<A: Aleo> ToFields for Future<A> {
     type Field = Field<A>;
 
     /// Returns the circuit future as a list of fields.
+    ///
+    /// The fields pack the program ID, the function name, and then each argument, in that order,
+    /// and must match the output of `console::Future::to_fields` exactly.
     #[inline]
     fn to_fields(&self) -> Vec<Field<A>> {
         // Encode the data as little-endian bits.
@@ -34,3 +37,27 @@ impl<A: Aleo> ToFields for Future<A> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use console::ToFields as _;
+
+    use anyhow::Result;
+
+    #[test]
+    fn test_to_fields_matches_console() -> Result<()> {
+        let expected = console::Future::<<Circuit as Environment>::Network>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [ 1u64 ] }, { a: 1u8, b: 2field }, 5u64 ] }",
+        )?;
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Ensure the circuit and console fields agree.
+            let candidate = Future::<Circuit>::new(mode, expected.clone());
+            assert_eq!(expected.to_fields()?, candidate.to_fields().eject_value());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
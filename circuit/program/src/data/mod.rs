@@ -19,7 +19,7 @@ mod ciphertext;
 pub use ciphertext::Ciphertext;
 
 mod future;
-pub use future::{Argument, Future};
+pub use future::{enforce_future_arity, Argument, Future};
 
 pub(super) mod identifier;
 pub use identifier::Identifier;
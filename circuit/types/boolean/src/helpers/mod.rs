@@ -18,5 +18,6 @@ pub mod adder;
 pub mod bits_are_zero;
 pub mod comparator;
 pub mod from_bits;
+pub mod selects_index;
 pub mod subtractor;
 pub mod to_bits;
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Boolean<E> {
+    /// Asserts that the one-hot `selector` selects the index given by `index_bits_le`.
+    /// This *internal* function assumes the index is in **little-endian** representation.
+    #[doc(hidden)]
+    pub fn assert_selects_index(selector: &[Boolean<E>], index_bits_le: &[Boolean<E>]) {
+        let mut sum = Self::constant(false).0;
+        for (i, bit) in selector.iter().enumerate() {
+            // Determine whether the index is equal to `i`.
+            let is_index = match i.checked_shr(index_bits_le.len() as u32).unwrap_or(0) == 0 {
+                true => index_bits_le.iter().enumerate().fold(Boolean::constant(true), |is_index, (j, index_bit)| {
+                    match i.checked_shr(j as u32).unwrap_or(0) & 1 == 1 {
+                        true => is_index.bitand(index_bit),
+                        false => is_index.bitand(&!index_bit),
+                    }
+                }),
+                // If `i` is not representable in `index_bits_le`, then it cannot be the index.
+                false => Boolean::constant(false),
+            };
+            // Assert that the selector bit is set if and only if the index is `i`.
+            E::assert_eq(bit, is_index);
+            sum += &**bit;
+        }
+        // Assert that exactly one selector bit is set.
+        E::assert_eq(sum, E::one());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_assert_selects_index(
+        selector: &[bool],
+        index: u8,
+        mode: Mode,
+        is_satisfied: bool,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let case = format!("{mode} {selector:?} {index}");
+        let selector = selector.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+        let index_bits_le = (0..8).map(|j| Boolean::<Circuit>::new(mode, (index >> j) & 1 == 1)).collect::<Vec<_>>();
+        Circuit::scope(case, || {
+            Boolean::assert_selects_index(&selector, &index_bits_le);
+            match is_satisfied {
+                true => assert_scope!(num_constants, num_public, num_private, num_constraints),
+                false => assert_scope_fails!(num_constants, num_public, num_private, num_constraints),
+            }
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_assert_selects_index_constant() {
+        // The selector matches the index.
+        check_assert_selects_index(&[true, false, false, false], 0, Mode::Constant, true, 0, 0, 0, 0);
+        check_assert_selects_index(&[false, false, true, false], 2, Mode::Constant, true, 0, 0, 0, 0);
+        check_assert_selects_index(&[false, false, false, true], 3, Mode::Constant, true, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_assert_selects_index() {
+        for mode in [Mode::Public, Mode::Private] {
+            // The selector matches the index.
+            check_assert_selects_index(&[true, false, false, false], 0, mode, true, 0, 0, 28, 33);
+            check_assert_selects_index(&[false, false, true, false], 2, mode, true, 0, 0, 28, 33);
+            check_assert_selects_index(&[false, false, false, true], 3, mode, true, 0, 0, 28, 33);
+
+            // The selector does not match the index.
+            check_assert_selects_index(&[false, true, false, false], 2, mode, false, 0, 0, 28, 33);
+            // The selector is not one-hot.
+            check_assert_selects_index(&[false, true, true, false], 2, mode, false, 0, 0, 28, 33);
+            check_assert_selects_index(&[false, false, false, false], 2, mode, false, 0, 0, 28, 33);
+            // The index is out of range.
+            check_assert_selects_index(&[false, false, false, false], 4, mode, false, 0, 0, 28, 33);
+        }
+    }
+}
@@ -23,6 +23,15 @@ pub enum Argument<N: Network> {
     Future(Future<N>),
 }
 
+impl<N: Network> core::hash::Hash for Argument<N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Plaintext(plaintext) => plaintext.hash(state),
+            Self::Future(future) => future.hash(state),
+        }
+    }
+}
+
 impl<N: Network> Equal<Self> for Argument<N> {
     type Output = Boolean<N>;
 
@@ -46,16 +55,23 @@ impl<N: Network> Equal<Self> for Argument<N> {
 }
 
 impl<N: Network> FromBytes for Argument<N> {
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self>
+    fn read_le<R: Read>(reader: R) -> IoResult<Self>
     where
         Self: Sized,
     {
+        Self::read_le_internal(reader, 0)
+    }
+}
+
+impl<N: Network> Argument<N> {
+    /// Reads in an argument from a buffer, where `depth` is the depth of the enclosing future.
+    pub(super) fn read_le_internal<R: Read>(mut reader: R, depth: usize) -> IoResult<Self> {
         // Read the index.
         let index = u8::read_le(&mut reader)?;
         // Read the argument.
         let argument = match index {
             0 => Self::Plaintext(Plaintext::read_le(&mut reader)?),
-            1 => Self::Future(Future::read_le(&mut reader)?),
+            1 => Self::Future(Future::read_le_internal(&mut reader, depth + 1)?),
             2.. => return Err(error(format!("Failed to decode future argument {index}"))),
         };
         Ok(argument)
@@ -63,7 +79,14 @@ impl<N: Network> FromBytes for Argument<N> {
 }
 
 impl<N: Network> ToBytes for Argument<N> {
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_le_internal(writer, 0)
+    }
+}
+
+impl<N: Network> Argument<N> {
+    /// Writes an argument to a buffer, where `depth` is the depth of the enclosing future.
+    pub(super) fn write_le_internal<W: Write>(&self, mut writer: W, depth: usize) -> IoResult<()> {
         match self {
             Self::Plaintext(plaintext) => {
                 0u8.write_le(&mut writer)?;
@@ -71,7 +94,7 @@ impl<N: Network> ToBytes for Argument<N> {
             }
             Self::Future(future) => {
                 1u8.write_le(&mut writer)?;
-                future.write_le(&mut writer)
+                future.write_le_internal(&mut writer, depth + 1)
             }
         }
     }
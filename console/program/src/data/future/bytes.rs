@@ -16,7 +16,18 @@ use super::*;
 
 impl<N: Network> FromBytes for Future<N> {
     /// Reads in a future from a buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_internal(reader, 0)
+    }
+}
+
+impl<N: Network> Future<N> {
+    /// Reads in a future from a buffer, while tracking the depth of nested futures.
+    pub(super) fn read_le_internal<R: Read>(mut reader: R, depth: usize) -> IoResult<Self> {
+        // Ensure the future does not exceed the maximum depth.
+        if depth > Self::MAX_DEPTH {
+            return Err(error(format!("Failed to read future: exceeds the maximum depth of {}", Self::MAX_DEPTH)));
+        }
         // Read the program ID.
         let program_id = ProgramID::read_le(&mut reader)?;
         // Read the function name.
@@ -35,7 +46,7 @@ impl<N: Network> FromBytes for Future<N> {
             let mut bytes = Vec::new();
             (&mut reader).take(num_bytes as u64).read_to_end(&mut bytes)?;
             // Recover the argument.
-            let entry = Argument::read_le(&mut bytes.as_slice())?;
+            let entry = Argument::read_le_internal(&mut bytes.as_slice(), depth)?;
             // Add the argument.
             arguments.push(entry);
         }
@@ -44,9 +55,43 @@ impl<N: Network> FromBytes for Future<N> {
     }
 }
 
+impl<N: Network> Future<N> {
+    /// Reads in a future from a buffer, and returns it with the number of bytes read.
+    pub fn read_le_counted<R: Read>(reader: R) -> Result<(Self, usize)> {
+        /// A reader that counts the number of bytes read.
+        struct CountingReader<R: Read> {
+            reader: R,
+            num_bytes: usize,
+        }
+
+        impl<R: Read> Read for CountingReader<R> {
+            fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+                let num_bytes = self.reader.read(buf)?;
+                self.num_bytes += num_bytes;
+                Ok(num_bytes)
+            }
+        }
+
+        let mut reader = CountingReader { reader, num_bytes: 0 };
+        let future = Self::read_le(&mut reader)?;
+        Ok((future, reader.num_bytes))
+    }
+}
+
 impl<N: Network> ToBytes for Future<N> {
     /// Writes a future to a buffer.
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_le_internal(writer, 0)
+    }
+}
+
+impl<N: Network> Future<N> {
+    /// Writes a future to a buffer, while tracking the depth of nested futures.
+    pub(super) fn write_le_internal<W: Write>(&self, mut writer: W, depth: usize) -> IoResult<()> {
+        // Ensure the future does not exceed the maximum depth.
+        if depth > Self::MAX_DEPTH {
+            return Err(error(format!("Failed to write future: exceeds the maximum depth of {}", Self::MAX_DEPTH)));
+        }
         // Write the program ID.
         self.program_id.write_le(&mut writer)?;
         // Write the function name.
@@ -59,7 +104,8 @@ impl<N: Network> ToBytes for Future<N> {
         // Write each argument.
         for argument in &self.arguments {
             // Write the argument (performed in 2 steps to prevent infinite recursion).
-            let bytes = argument.to_bytes_le().map_err(error)?;
+            let mut bytes = Vec::new();
+            argument.write_le_internal(&mut bytes, depth)?;
             // Write the number of bytes.
             u16::try_from(bytes.len()).map_err(error)?.write_le(&mut writer)?;
             // Write the bytes.
@@ -88,4 +134,115 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_nested() -> Result<()> {
+        // Check a 3-level-deep nested future.
+        let expected = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [ { program_id: token.aleo, function_name: burn, arguments: [ 1u64 ] }, 2u64 ] }, 3u64 ] }",
+        )?;
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Future::read_le(&expected_bytes[..])?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_exceeds_max_depth() -> Result<()> {
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let function_name = Identifier::from_str("transfer")?;
+
+        // Construct a future with nested futures up to the maximum depth.
+        let mut future = Future::<CurrentNetwork>::new(program_id, function_name, vec![]);
+        for _ in 0..Future::<CurrentNetwork>::MAX_DEPTH {
+            future = Future::new(program_id, function_name, vec![Argument::Future(future)]);
+        }
+        // Ensure the future at the maximum depth round trips.
+        let inner_bytes = future.to_bytes_le()?;
+        assert_eq!(future, Future::read_le(&inner_bytes[..])?);
+
+        // Ensure a future exceeding the maximum depth fails to be written.
+        let future = Future::new(program_id, function_name, vec![Argument::Future(future)]);
+        assert!(future.to_bytes_le().is_err());
+
+        // Ensure a future exceeding the maximum depth fails to be read, by encoding the outermost level manually.
+        let mut bytes = [program_id.to_bytes_le()?, function_name.to_bytes_le()?, vec![1u8]].concat();
+        let argument_bytes = [vec![1u8], inner_bytes].concat();
+        bytes.extend_from_slice(&u16::try_from(argument_bytes.len())?.to_le_bytes());
+        bytes.extend_from_slice(&argument_bytes);
+        assert!(Future::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_with_too_many_arguments() -> Result<()> {
+        let future =
+            Future::<CurrentNetwork>::from_str("{ program_id: credits.aleo, function_name: transfer, arguments: [] }")?;
+        let mut bytes = future.to_bytes_le()?;
+
+        // Declare an absurd number of arguments, without providing any of them.
+        *bytes.last_mut().unwrap() = u8::MAX;
+        assert!(Future::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+        // Declare one more argument than is allowed.
+        *bytes.last_mut().unwrap() = u8::try_from(CurrentNetwork::MAX_INPUTS).unwrap() + 1;
+        assert!(Future::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_argument_exceeds_max_size() -> Result<()> {
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let function_name = Identifier::from_str("transfer")?;
+
+        // Construct a 32 x 32 array of field elements, which fits within the `u16` length prefix of an argument.
+        let row = format!("[ {} ]", vec!["1field"; 32].join(", "));
+        let argument = Argument::Plaintext(Plaintext::from_str(&format!("[ {} ]", vec![row; 32].join(", ")))?);
+        assert!(argument.to_bytes_le()?.len() <= u16::MAX as usize);
+        // Ensure a future with the maximum number of such arguments round trips.
+        let future =
+            Future::<CurrentNetwork>::new(program_id, function_name, vec![argument; CurrentNetwork::MAX_INPUTS]);
+        assert_eq!(future, Future::read_le(&future.to_bytes_le()?[..])?);
+
+        // Construct a 32 x 32 x 2 array of field elements, which does not fit within the `u16` length prefix.
+        let pair = "[ 1field, 1field ]";
+        let row = format!("[ {} ]", vec![pair; 32].join(", "));
+        let argument = Argument::Plaintext(Plaintext::from_str(&format!("[ {} ]", vec![row; 32].join(", ")))?);
+        assert!(argument.to_bytes_le()?.len() > u16::MAX as usize);
+        // Ensure the future fails to be written, as it could not be read back.
+        let future = Future::<CurrentNetwork>::new(program_id, function_name, vec![argument]);
+        assert!(future.to_bytes_le().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_le_counted() -> Result<()> {
+        let first = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { a: 1u8, b: 2u8 }, 5u64 ] }",
+        )?;
+        let second = Future::<CurrentNetwork>::from_str(
+            "{ program_id: token.aleo, function_name: mint, arguments: [ { program_id: credits.aleo, function_name: transfer, arguments: [] } ] }",
+        )?;
+
+        // Concatenate the futures.
+        let first_bytes = first.to_bytes_le()?;
+        let bytes = [first_bytes.clone(), second.to_bytes_le()?].concat();
+
+        // Read the first future.
+        let (candidate, num_bytes) = Future::<CurrentNetwork>::read_le_counted(&bytes[..])?;
+        assert_eq!(first, candidate);
+        assert_eq!(first_bytes.len(), num_bytes);
+
+        // Read the second future.
+        let (candidate, num_bytes) = Future::<CurrentNetwork>::read_le_counted(&bytes[num_bytes..])?;
+        assert_eq!(second, candidate);
+        assert_eq!(bytes.len(), first_bytes.len() + num_bytes);
+
+        Ok(())
+    }
 }
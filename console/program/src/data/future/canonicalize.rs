@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexMap;
+
+impl<N: Network> Future<N> {
+    /// Returns a copy of the future with the argument at the given index replaced by `value`.
+    /// The result is canonicalized, so that the members of every struct in the future are sorted by name.
+    /// As with `Future::canonicalize`, the result is only for comparison and hashing, not for execution.
+    pub fn set_input_canonical(&self, index: usize, value: Argument<N>) -> Result<Future<N>> {
+        // Ensure the index is in range.
+        ensure!(index < self.arguments.len(), "Argument index {index} is out of range for future '{self}'");
+        // Replace the argument.
+        let mut arguments = self.arguments.clone();
+        arguments[index] = value;
+        // Return the canonicalized future.
+        let mut future = Self::new(self.program_id, self.function_name, arguments);
+        future.canonicalize();
+        Ok(future)
+    }
+
+    /// Converts the future into its canonical form, in which the members of every struct are sorted by name.
+    /// Futures that only differ in the order of their struct members serialize identically once canonicalized.
+    ///
+    /// The canonical form is only for comparing and hashing futures. Elsewhere, struct members are ordered
+    /// as in their struct definition, which is the order the VM checks them in during type matching, and which
+    /// determines `to_fields` and the future ID. Hence, a canonicalized future may fail type matching and
+    /// differ from the future on chain, so it must not be executed or serialized into a transaction.
+    pub fn canonicalize(&mut self) {
+        for argument in self.arguments.iter_mut() {
+            match argument {
+                Argument::Plaintext(plaintext) => *plaintext = canonicalize_plaintext(plaintext),
+                Argument::Future(future) => future.canonicalize(),
+            }
+        }
+    }
+}
+
+/// Returns the canonical form of the plaintext, in which the members of every struct are sorted by name.
+fn canonicalize_plaintext<N: Network>(plaintext: &Plaintext<N>) -> Plaintext<N> {
+    match plaintext {
+        Plaintext::Literal(..) => plaintext.clone(),
+        Plaintext::Struct(members, ..) => {
+            let mut members = members
+                .iter()
+                .map(|(name, member)| (*name, canonicalize_plaintext(member)))
+                .collect::<IndexMap<_, _>>();
+            members.sort_keys();
+            Plaintext::Struct(members, Default::default())
+        }
+        Plaintext::Array(elements, ..) => {
+            Plaintext::Array(elements.iter().map(canonicalize_plaintext).collect(), Default::default())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_set_input_canonical() -> Result<()> {
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64, { program_id: token.aleo, function_name: mint, arguments: [ { b: 1u8, a: 2u8 } ] } ] }",
+        )?;
+
+        // Insert a struct with unsorted fields, including a nested struct in an array.
+        let value = Plaintext::from_str("{ c: [ { z: true, y: false } ], a: 3u64 }")?;
+        let candidate = future.set_input_canonical(0, Argument::Plaintext(value))?;
+
+        // Ensure the result is canonical.
+        let expected = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { a: 3u64, c: [ { y: false, z: true } ] }, { program_id: token.aleo, function_name: mint, arguments: [ { a: 2u8, b: 1u8 } ] } ] }",
+        )?;
+        assert_eq!(candidate.to_string(), expected.to_string());
+        // Ensure canonicalizing again is a no-op.
+        let argument = candidate.arguments()[0].clone();
+        assert_eq!(candidate.set_input_canonical(0, argument)?.to_string(), candidate.to_string());
+
+        // Ensure an out-of-range index is rejected.
+        assert!(future.set_input_canonical(2, Argument::Plaintext(Plaintext::from_str("1u8")?)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_canonicalize() -> Result<()> {
+        let mut first = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { a: 1u8, b: [ { x: 1u8, y: 2u8 } ] }, { program_id: token.aleo, function_name: mint, arguments: [ { c: true, d: false } ] } ] }",
+        )?;
+        let mut second = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { b: [ { y: 2u8, x: 1u8 } ], a: 1u8 }, { program_id: token.aleo, function_name: mint, arguments: [ { d: false, c: true } ] } ] }",
+        )?;
+        assert_ne!(first.to_bytes_le()?, second.to_bytes_le()?);
+
+        // Ensure the futures serialize identically once canonicalized.
+        first.canonicalize();
+        second.canonicalize();
+        assert_eq!(first.to_bytes_le()?, second.to_bytes_le()?);
+        assert_eq!(first.to_string(), second.to_string());
+
+        // Ensure canonicalizing an already canonical future is a no-op.
+        let expected = first.to_bytes_le()?;
+        first.canonicalize();
+        assert_eq!(first.to_bytes_le()?, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The result of comparing two futures.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FutureComparison {
+    /// The futures are equal.
+    Same,
+    /// The futures have different program IDs.
+    DifferentProgramID,
+    /// The futures have the same program ID, but different function names.
+    DifferentFunction,
+    /// The futures have the same program ID and function name, but different arguments.
+    DifferentArguments,
+}
+
+impl<N: Network> Future<N> {
+    /// Compares `self` with `other`, and returns the first component in which they differ.
+    pub fn compare(&self, other: &Self) -> FutureComparison {
+        if self.program_id != other.program_id {
+            FutureComparison::DifferentProgramID
+        } else if self.function_name != other.function_name {
+            FutureComparison::DifferentFunction
+        } else if self.arguments.len() != other.arguments.len()
+            || !self.arguments.iter().zip_eq(&other.arguments).all(|(a, b)| *a.is_equal(b))
+        {
+            FutureComparison::DifferentArguments
+        } else {
+            FutureComparison::Same
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_compare() -> Result<()> {
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64 ] }",
+        )?;
+
+        // Check the same future.
+        assert_eq!(future.compare(&future.clone()), FutureComparison::Same);
+
+        // Check a different program ID.
+        let other = Future::<CurrentNetwork>::from_str(
+            "{ program_id: token.aleo, function_name: transfer, arguments: [ 1u64 ] }",
+        )?;
+        assert_eq!(future.compare(&other), FutureComparison::DifferentProgramID);
+
+        // Check a different function name.
+        let other = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: mint, arguments: [ 1u64 ] }",
+        )?;
+        assert_eq!(future.compare(&other), FutureComparison::DifferentFunction);
+
+        // Check different arguments.
+        let other = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 2u64 ] }",
+        )?;
+        assert_eq!(future.compare(&other), FutureComparison::DifferentArguments);
+        let other = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64, 2u64 ] }",
+        )?;
+        assert_eq!(future.compare(&other), FutureComparison::DifferentArguments);
+
+        Ok(())
+    }
+}
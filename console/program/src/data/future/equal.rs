@@ -23,6 +23,14 @@ impl<N: Network> PartialEq for Future<N> {
     }
 }
 
+impl<N: Network> core::hash::Hash for Future<N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.program_id.hash(state);
+        self.function_name.hash(state);
+        self.arguments.hash(state);
+    }
+}
+
 impl<N: Network> Equal<Self> for Future<N> {
     type Output = Boolean<N>;
 
@@ -56,3 +64,95 @@ impl<N: Network> Equal<Self> for Future<N> {
         !self.is_equal(other)
     }
 }
+
+impl<N: Network> Future<N> {
+    /// Returns `true` if `self` and `other` are equal up to `max_depth` levels of futures.
+    /// Nested futures beyond `max_depth` are not compared, and are assumed to be equal.
+    ///
+    /// Note: This method does **not** guarantee that `self` and `other` are equal,
+    /// and `self == other` should be used when full equality is required.
+    pub fn shallow_eq(&self, other: &Self, max_depth: usize) -> bool {
+        // If the maximum depth is reached, the futures are assumed to be equal.
+        if max_depth == 0 {
+            return true;
+        }
+        // Check the `program_id`, `function_name`, and number of arguments.
+        if self.program_id != other.program_id
+            || self.function_name != other.function_name
+            || self.arguments.len() != other.arguments.len()
+        {
+            return false;
+        }
+        // Check each argument, descending into nested futures until the maximum depth is reached.
+        self.arguments.iter().zip_eq(&other.arguments).all(|(argument_a, argument_b)| match (argument_a, argument_b) {
+            (Argument::Plaintext(plaintext_a), Argument::Plaintext(plaintext_b)) => plaintext_a == plaintext_b,
+            (Argument::Future(future_a), Argument::Future(future_b)) => future_a.shallow_eq(future_b, max_depth - 1),
+            (Argument::Plaintext(..), _) | (Argument::Future(..), _) => false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    use std::collections::HashMap;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_hash() -> Result<()> {
+        let string = "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [ 1u64 ] }, { a: 1u8, b: [ true, false ] }, 5u64 ] }";
+
+        // Construct two futures independently.
+        let a = Future::<CurrentNetwork>::from_str(string)?;
+        let b = Future::<CurrentNetwork>::from_str(string)?;
+        assert_eq!(a, b);
+
+        // Ensure equal futures collide in a map.
+        let mut map = HashMap::new();
+        map.insert(a, 1);
+        map.insert(b, 2);
+        assert_eq!(map.len(), 1);
+
+        // Ensure a different future does not collide.
+        let c = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 6u64 ] }",
+        )?;
+        assert_ne!(map.keys().next().unwrap(), &c);
+        map.insert(c, 3);
+        assert_eq!(map.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shallow_eq() -> Result<()> {
+        let a = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [ 1u64 ] }, 5u64 ] }",
+        )?;
+        let b = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [ 2u64 ] }, 5u64 ] }",
+        )?;
+
+        // Ensure the futures are only equal when the nested future is not compared.
+        assert!(a.shallow_eq(&b, 0));
+        assert!(a.shallow_eq(&b, 1));
+        assert!(!a.shallow_eq(&b, 2));
+        assert_ne!(a, b);
+
+        // Ensure equal futures are equal at every depth.
+        for depth in 0..4 {
+            assert!(a.shallow_eq(&a.clone(), depth));
+        }
+
+        // Ensure differing top-level arguments are detected at depth 1.
+        let c = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [ 1u64 ] }, 6u64 ] }",
+        )?;
+        assert!(!a.shallow_eq(&c, 1));
+
+        Ok(())
+    }
+}
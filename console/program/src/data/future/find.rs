@@ -15,6 +15,23 @@
 use super::*;
 
 impl<N: Network> Future<N> {
+    /// Returns the nested future at the given argument index, if the argument is a future.
+    pub fn nested_future_at(&self, index: usize) -> Option<&Future<N>> {
+        match self.arguments.get(index) {
+            Some(Argument::Future(future)) => Some(future),
+            Some(Argument::Plaintext(..)) | None => None,
+        }
+    }
+
+    /// Returns an iterator over the plaintext arguments, along with their argument indices.
+    /// Nested futures are skipped, as they are not yet resolved to a value.
+    pub fn resolved_inputs(&self) -> impl Iterator<Item = (usize, &Plaintext<N>)> {
+        self.arguments.iter().enumerate().filter_map(|(index, argument)| match argument {
+            Argument::Plaintext(plaintext) => Some((index, plaintext)),
+            Argument::Future(..) => None,
+        })
+    }
+
     /// Returns a value from the given path.
     pub fn find<A: Into<Access<N>> + Copy + Debug>(&self, path: &[A]) -> Result<Value<N>> {
         // Ensure the path is not empty.
@@ -71,3 +88,89 @@ impl<N: Network> Future<N> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Returns the given path as a list of accesses.
+    fn path(accesses: &[&str]) -> Result<Vec<Access<CurrentNetwork>>> {
+        accesses.iter().map(|access| Access::from_str(access)).collect()
+    }
+
+    #[test]
+    fn test_find() -> Result<()> {
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64, { owner: aleo1g8qul5a44vk22u9uuvaewdcjw4v6xg8wx0llru39nnjn7eu08yrscxe4e2, amount: 5u64, data: [ 1u8, 2u8 ] }, { program_id: token.aleo, function_name: mint, arguments: [ 3u64 ] } ] }",
+        )?;
+
+        // Check a top-level argument.
+        assert_eq!(future.find(&path(&["[0u32]"])?)?, Value::from_str("1u64")?);
+        // Check a struct member of an argument.
+        assert_eq!(future.find(&path(&["[1u32]", ".amount"])?)?, Value::from_str("5u64")?);
+        // Check an array element within a struct member of an argument.
+        assert_eq!(future.find(&path(&["[1u32]", ".data", "[1u32]"])?)?, Value::from_str("2u8")?);
+        // Check an argument of a nested future.
+        assert_eq!(future.find(&path(&["[2u32]", "[0u32]"])?)?, Value::from_str("3u64")?);
+
+        // Check that an empty path fails.
+        assert!(future.find::<Access<CurrentNetwork>>(&[]).is_err());
+        // Check that an out-of-bounds argument fails.
+        assert!(future.find(&path(&["[3u32]"])?).is_err());
+        // Check that a missing member fails.
+        assert!(future.find(&path(&["[1u32]", ".fee"])?).is_err());
+        // Check that an out-of-bounds element fails.
+        assert!(future.find(&path(&["[1u32]", ".data", "[2u32]"])?).is_err());
+        // Check that a path not matching the shape fails.
+        assert!(future.find(&path(&["[0u32]", ".amount"])?).is_err());
+        assert!(future.find(&path(&[".amount"])?).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_future_at() -> Result<()> {
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64, { program_id: token.aleo, function_name: mint, arguments: [ 3u64 ] } ] }",
+        )?;
+
+        // Check a nested future argument.
+        let expected =
+            Future::<CurrentNetwork>::from_str("{ program_id: token.aleo, function_name: mint, arguments: [ 3u64 ] }")?;
+        assert_eq!(future.nested_future_at(1), Some(&expected));
+        // Check a literal argument.
+        assert_eq!(future.nested_future_at(0), None);
+        // Check an out-of-range argument.
+        assert_eq!(future.nested_future_at(2), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolved_inputs() -> Result<()> {
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [ 3u64 ] }, 1u64, { a: true }, { program_id: token.aleo, function_name: burn, arguments: [] }, 2u8 ] }",
+        )?;
+
+        // Ensure only the plaintext arguments are yielded, with their argument indices.
+        let candidate =
+            future.resolved_inputs().map(|(index, plaintext)| (index, plaintext.clone())).collect::<Vec<_>>();
+        let expected: Vec<(usize, Plaintext<CurrentNetwork>)> = vec![
+            (1, Plaintext::from_str("1u64")?),
+            (2, Plaintext::from_str("{ a: true }")?),
+            (4, Plaintext::from_str("2u8")?),
+        ];
+        assert_eq!(candidate, expected);
+
+        // Ensure a future without plaintext arguments yields nothing.
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: burn, arguments: [] } ] }",
+        )?;
+        assert_eq!(future.resolved_inputs().count(), 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Future<N> {
+    /// Returns a fingerprint of the shape of the future, which is independent of the values of its arguments.
+    /// The shape consists of the program ID, the function name, and the type of each argument.
+    pub fn shape_fingerprint(&self) -> Result<Field<N>> {
+        let mut bits_le = Vec::new();
+        self.write_shape_bits_le(&mut bits_le)?;
+        N::hash_bhp1024(&bits_le)
+    }
+
+    /// Returns the ID of the future, as the Poseidon hash of its field elements.
+    /// Unlike the shape fingerprint, the ID commits to the values of the arguments,
+    /// so it uniquely identifies the future and may be used as a map key.
+    pub fn to_id(&self) -> Result<Field<N>> {
+        N::hash_psd8(&self.to_fields()?)
+    }
+
+    /// Writes the shape of the future as a list of **little-endian** bits.
+    fn write_shape_bits_le(&self, vec: &mut Vec<bool>) -> Result<()> {
+        /// Writes the given identifier, prefixed by its size in bits.
+        fn write_identifier<N: Network>(identifier: &Identifier<N>, vec: &mut Vec<bool>) {
+            identifier.size_in_bits().write_bits_le(vec);
+            identifier.write_bits_le(vec);
+        }
+
+        /// Writes the shape of the given plaintext.
+        fn write_plaintext_shape<N: Network>(plaintext: &Plaintext<N>, vec: &mut Vec<bool>) -> Result<()> {
+            match plaintext {
+                Plaintext::Literal(literal, ..) => {
+                    0u8.write_bits_le(vec);
+                    literal.to_type().type_id().write_bits_le(vec);
+                }
+                Plaintext::Struct(members, ..) => {
+                    1u8.write_bits_le(vec);
+                    u32::try_from(members.len())?.write_bits_le(vec);
+                    for (name, member) in members {
+                        write_identifier(name, vec);
+                        write_plaintext_shape(member, vec)?;
+                    }
+                }
+                Plaintext::Array(elements, ..) => {
+                    2u8.write_bits_le(vec);
+                    u32::try_from(elements.len())?.write_bits_le(vec);
+                    for element in elements {
+                        write_plaintext_shape(element, vec)?;
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        // Write the program ID and function name.
+        write_identifier(self.program_id.name(), vec);
+        write_identifier(self.program_id.network(), vec);
+        write_identifier(&self.function_name, vec);
+        // Write the number of arguments.
+        u32::try_from(self.arguments.len())?.write_bits_le(vec);
+        // Write the shape of each argument.
+        for argument in &self.arguments {
+            match argument {
+                Argument::Plaintext(plaintext) => {
+                    false.write_bits_le(vec);
+                    write_plaintext_shape(plaintext, vec)?;
+                }
+                Argument::Future(future) => {
+                    true.write_bits_le(vec);
+                    future.write_shape_bits_le(vec)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Returns the shape fingerprint of the given future string.
+    fn fingerprint(string: &str) -> Result<Field<CurrentNetwork>> {
+        Future::<CurrentNetwork>::from_str(string)?.shape_fingerprint()
+    }
+
+    #[test]
+    fn test_shape_fingerprint() -> Result<()> {
+        let expected = fingerprint(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { a: 1u8, b: [ 1u64, 2u64 ] }, { program_id: token.aleo, function_name: mint, arguments: [ 1field ] } ] }",
+        )?;
+
+        // Ensure futures with the same shape and different values share a fingerprint.
+        let candidate = fingerprint(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { a: 7u8, b: [ 8u64, 9u64 ] }, { program_id: token.aleo, function_name: mint, arguments: [ 2field ] } ] }",
+        )?;
+        assert_eq!(expected, candidate);
+
+        // Ensure futures with different shapes have different fingerprints.
+        for string in [
+            // A different function name.
+            "{ program_id: credits.aleo, function_name: burn, arguments: [ { a: 1u8, b: [ 1u64, 2u64 ] }, { program_id: token.aleo, function_name: mint, arguments: [ 1field ] } ] }",
+            // A different literal type.
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { a: 1u16, b: [ 1u64, 2u64 ] }, { program_id: token.aleo, function_name: mint, arguments: [ 1field ] } ] }",
+            // A different member name.
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { c: 1u8, b: [ 1u64, 2u64 ] }, { program_id: token.aleo, function_name: mint, arguments: [ 1field ] } ] }",
+            // A different array length.
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { a: 1u8, b: [ 1u64 ] }, { program_id: token.aleo, function_name: mint, arguments: [ 1field ] } ] }",
+            // A different nested future.
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { a: 1u8, b: [ 1u64, 2u64 ] }, { program_id: token.aleo, function_name: mint, arguments: [ 1field, 2field ] } ] }",
+        ] {
+            assert_ne!(expected, fingerprint(string)?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_id() -> Result<()> {
+        let string = "{ program_id: credits.aleo, function_name: transfer, arguments: [ { a: 1u8, b: [ 1u64, 2u64 ] }, { program_id: token.aleo, function_name: mint, arguments: [ 1field ] } ] }";
+        let expected = Future::<CurrentNetwork>::from_str(string)?.to_id()?;
+
+        // Ensure structurally-equal futures share an ID.
+        let candidate = Future::<CurrentNetwork>::from_str(string)?;
+        assert_eq!(expected, candidate.to_id()?);
+        assert_eq!(expected, Future::<CurrentNetwork>::from_bytes_le(&candidate.to_bytes_le()?)?.to_id()?);
+
+        // Ensure futures with different values have different IDs.
+        for string in [
+            // A different function name.
+            "{ program_id: credits.aleo, function_name: burn, arguments: [ { a: 1u8, b: [ 1u64, 2u64 ] }, { program_id: token.aleo, function_name: mint, arguments: [ 1field ] } ] }",
+            // A different argument value.
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { a: 2u8, b: [ 1u64, 2u64 ] }, { program_id: token.aleo, function_name: mint, arguments: [ 1field ] } ] }",
+            // A different nested future argument.
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { a: 1u8, b: [ 1u64, 2u64 ] }, { program_id: token.aleo, function_name: mint, arguments: [ 2field ] } ] }",
+            // No arguments.
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [] }",
+        ] {
+            assert_ne!(expected, Future::<CurrentNetwork>::from_str(string)?.to_id()?);
+        }
+
+        Ok(())
+    }
+}
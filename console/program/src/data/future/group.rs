@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::BTreeMap;
+
+/// The futures for each call target, given as a program ID and function name.
+pub type FuturesByTarget<N> = BTreeMap<(ProgramID<N>, Identifier<N>), Vec<Future<N>>>;
+
+/// Partitions the given futures by their call target, preserving the order of futures within each target.
+pub fn group_futures_by_target<N: Network>(futures: Vec<Future<N>>) -> FuturesByTarget<N> {
+    let mut groups = BTreeMap::<_, Vec<_>>::new();
+    for future in futures {
+        groups.entry((*future.program_id(), *future.function_name())).or_default().push(future);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_group_futures_by_target() -> Result<()> {
+        let futures = [
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64 ] }",
+            "{ program_id: token.aleo, function_name: mint, arguments: [ 2u64 ] }",
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 3u64 ] }",
+            "{ program_id: token.aleo, function_name: mint, arguments: [ 4u64 ] }",
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 5u64 ] }",
+        ]
+        .into_iter()
+        .map(Future::<CurrentNetwork>::from_str)
+        .collect::<Result<Vec<_>>>()?;
+
+        let groups = group_futures_by_target(futures.clone());
+        assert_eq!(groups.len(), 2);
+
+        // Check the futures calling `credits.aleo/transfer`.
+        let target = (ProgramID::from_str("credits.aleo")?, Identifier::from_str("transfer")?);
+        assert_eq!(groups[&target], vec![futures[0].clone(), futures[2].clone(), futures[4].clone()]);
+
+        // Check the futures calling `token.aleo/mint`.
+        let target = (ProgramID::from_str("token.aleo")?, Identifier::from_str("mint")?);
+        assert_eq!(groups[&target], vec![futures[1].clone(), futures[3].clone()]);
+
+        Ok(())
+    }
+}
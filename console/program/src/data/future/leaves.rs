@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use crate::Literal;
+
+impl<N: Network> Future<N> {
+    /// Returns an iterator over the literal leaves in the arguments of the future, including those of nested futures.
+    /// The leaves are yielded in depth-first argument order, descending into struct members and array elements.
+    pub fn leaves(&self) -> impl Iterator<Item = &Literal<N>> {
+        /// Appends the literal leaves in the given plaintext to `leaves`.
+        fn plaintext_leaves<'a, N: Network>(plaintext: &'a Plaintext<N>, leaves: &mut Vec<&'a Literal<N>>) {
+            match plaintext {
+                Plaintext::Literal(literal, ..) => leaves.push(literal),
+                Plaintext::Struct(members, ..) => members.values().for_each(|member| plaintext_leaves(member, leaves)),
+                Plaintext::Array(elements, ..) => elements.iter().for_each(|element| plaintext_leaves(element, leaves)),
+            }
+        }
+
+        /// Appends the literal leaves in the given future to `leaves`.
+        fn future_leaves<'a, N: Network>(future: &'a Future<N>, leaves: &mut Vec<&'a Literal<N>>) {
+            for argument in future.arguments.iter() {
+                match argument {
+                    Argument::Plaintext(plaintext) => plaintext_leaves(plaintext, leaves),
+                    Argument::Future(future) => future_leaves(future, leaves),
+                }
+            }
+        }
+
+        let mut leaves = Vec::with_capacity(self.num_leaves());
+        future_leaves(self, &mut leaves);
+        leaves.into_iter()
+    }
+
+    /// Returns the total number of literal leaves in the arguments of the future, including those of nested futures.
+    /// No maximum is enforced on this count; callers which meter argument complexity must apply their own limit.
+    pub fn num_leaves(&self) -> usize {
+        /// Returns the number of literal leaves in the given plaintext.
+        fn num_plaintext_leaves<N: Network>(plaintext: &Plaintext<N>) -> usize {
+            match plaintext {
+                Plaintext::Literal(..) => 1,
+                Plaintext::Struct(members, ..) => members.values().map(num_plaintext_leaves).sum(),
+                Plaintext::Array(elements, ..) => elements.iter().map(num_plaintext_leaves).sum(),
+            }
+        }
+
+        self.arguments
+            .iter()
+            .map(|argument| match argument {
+                Argument::Plaintext(plaintext) => num_plaintext_leaves(plaintext),
+                Argument::Future(future) => future.num_leaves(),
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_num_leaves() -> Result<()> {
+        // Check a future with no arguments.
+        let future =
+            Future::<CurrentNetwork>::from_str("{ program_id: credits.aleo, function_name: transfer, arguments: [] }")?;
+        assert_eq!(future.num_leaves(), 0);
+
+        // Check a future with literal arguments.
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64, 2u64, true ] }",
+        )?;
+        assert_eq!(future.num_leaves(), 3);
+
+        // Check a future with a nested struct argument.
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { a: 1u8, b: { c: [ 1u8, 2u8 ], d: 3field } }, 5u64 ] }",
+        )?;
+        assert_eq!(future.num_leaves(), 5);
+
+        // Check a future with a nested future argument.
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [ 1u64, 2u64 ] }, 5u64 ] }",
+        )?;
+        assert_eq!(future.num_leaves(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_leaves() -> Result<()> {
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u8, { a: 2u8, b: { c: [ 3u8, 4u8 ], d: 5u8 } }, [ { e: 6u8 }, { e: 7u8 } ], { program_id: token.aleo, function_name: mint, arguments: [ 8u8 ] }, 9u8 ] }",
+        )?;
+
+        // Ensure the leaves are yielded in depth-first argument order.
+        let candidate = future.leaves().map(|literal| literal.to_string()).collect::<Vec<_>>();
+        let expected = (1..=9).map(|i| format!("{i}u8")).collect::<Vec<_>>();
+        assert_eq!(candidate, expected);
+        assert_eq!(future.leaves().count(), future.num_leaves());
+
+        // Ensure a future with no arguments has no leaves.
+        let future =
+            Future::<CurrentNetwork>::from_str("{ program_id: credits.aleo, function_name: transfer, arguments: [] }")?;
+        assert_eq!(future.leaves().count(), 0);
+
+        Ok(())
+    }
+}
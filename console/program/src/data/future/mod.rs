@@ -15,13 +15,30 @@
 mod argument;
 pub use argument::Argument;
 
+mod compare;
+pub use compare::FutureComparison;
+
+mod group;
+pub use group::{group_futures_by_target, FuturesByTarget};
+
+mod parse;
+pub use parse::FutureParseError;
+
 mod bytes;
+mod canonicalize;
 mod equal;
 mod find;
-mod parse;
+mod fingerprint;
+mod leaves;
 mod serialize;
+mod simplify;
+mod size_in_bits;
+mod size_in_fields;
 mod to_bits;
+mod to_dot;
 mod to_fields;
+mod to_json;
+mod validate;
 
 use crate::{Access, Identifier, Plaintext, ProgramID, Value};
 use snarkvm_console_network::Network;
@@ -41,12 +58,49 @@ pub struct Future<N: Network> {
 }
 
 impl<N: Network> Future<N> {
+    /// The maximum number of futures that may be nested beneath a top-level future.
+    /// A future nests one level for each call into an imported program, so this is bounded by the program depth.
+    pub const MAX_DEPTH: usize = N::MAX_PROGRAM_DEPTH;
+
     /// Initializes a new future.
     #[inline]
     pub const fn new(program_id: ProgramID<N>, function_name: Identifier<N>, arguments: Vec<Argument<N>>) -> Self {
         Self { program_id, function_name, arguments }
     }
 
+    /// Initializes a new future, ensuring the number of arguments matches the arity of the called function.
+    /// This should be preferred over `Future::new` when the arguments originate from outside of the VM.
+    pub fn new_checked(
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        arguments: Vec<Argument<N>>,
+        expected_arity: usize,
+    ) -> Result<Self> {
+        ensure!(
+            arguments.len() == expected_arity,
+            "Future '{program_id}/{function_name}' expects {expected_arity} arguments, found {}",
+            arguments.len()
+        );
+        Ok(Self::new(program_id, function_name, arguments))
+    }
+
+    /// Initializes a future with no arguments, which may serve as a placeholder for the given call target.
+    #[inline]
+    pub const fn empty(program_id: ProgramID<N>, function_name: Identifier<N>) -> Self {
+        Self::new(program_id, function_name, Vec::new())
+    }
+
+    /// Returns the future with the argument at the given index replaced by the given plaintext.
+    pub fn with_input(mut self, index: usize, value: Plaintext<N>) -> Result<Self> {
+        match self.arguments.get_mut(index) {
+            Some(argument) => *argument = Argument::Plaintext(value),
+            None => {
+                bail!("Argument index {index} is out of range for future '{}/{}'", self.program_id, self.function_name)
+            }
+        }
+        Ok(self)
+    }
+
     /// Returns the program ID.
     #[inline]
     pub const fn program_id(&self) -> &ProgramID<N> {
@@ -64,4 +118,83 @@ impl<N: Network> Future<N> {
     pub fn arguments(&self) -> &[Argument<N>] {
         &self.arguments
     }
+
+    /// Returns `true` if the future has no arguments.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.arguments.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_new_checked() -> Result<()> {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("credits.aleo")?;
+        let function_name = Identifier::from_str("transfer")?;
+        let arguments =
+            vec![Argument::Plaintext(Plaintext::from_str("1u64")?), Argument::Plaintext(Plaintext::from_str("2u64")?)];
+
+        // Ensure a matching arity succeeds.
+        let candidate = Future::new_checked(program_id, function_name, arguments.clone(), 2)?;
+        assert_eq!(candidate, Future::new(program_id, function_name, arguments.clone()));
+
+        // Ensure a mismatched arity fails.
+        assert!(Future::new_checked(program_id, function_name, arguments.clone(), 1).is_err());
+        assert!(Future::new_checked(program_id, function_name, arguments, 3).is_err());
+        assert!(Future::new_checked(program_id, function_name, vec![], 1).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_input() -> Result<()> {
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64, { program_id: token.aleo, function_name: mint, arguments: [] } ] }",
+        )?;
+
+        // Ensure a plaintext argument is replaced.
+        let candidate = future.clone().with_input(0, Plaintext::from_str("2u64")?)?;
+        let expected = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 2u64, { program_id: token.aleo, function_name: mint, arguments: [] } ] }",
+        )?;
+        assert_eq!(candidate, expected);
+
+        // Ensure a nested future argument is replaced.
+        let candidate = future.clone().with_input(1, Plaintext::from_str("{ a: 1u8 }")?)?;
+        let expected = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64, { a: 1u8 } ] }",
+        )?;
+        assert_eq!(candidate, expected);
+
+        // Ensure an out-of-range index is rejected.
+        let error = future.clone().with_input(2, Plaintext::from_str("2u64")?).unwrap_err();
+        assert_eq!(error.to_string(), "Argument index 2 is out of range for future 'credits.aleo/transfer'");
+        assert!(future.with_input(usize::MAX, Plaintext::from_str("2u64")?).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty() -> Result<()> {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("credits.aleo")?;
+        let function_name = Identifier::from_str("transfer")?;
+
+        // Ensure the empty future has no arguments.
+        let future = Future::empty(program_id, function_name);
+        assert!(future.arguments().is_empty());
+        assert!(future.is_empty());
+        assert_eq!(future, Future::new(program_id, function_name, vec![]));
+
+        // Ensure a future with arguments is not empty.
+        let future = Future::new(program_id, function_name, vec![Argument::Future(future)]);
+        assert!(!future.is_empty());
+
+        Ok(())
+    }
 }
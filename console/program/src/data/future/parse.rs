@@ -14,6 +14,102 @@
 
 use super::*;
 
+/// The reason a string failed to parse into a future.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FutureParseError {
+    /// The braces and brackets in the string are not balanced.
+    UnbalancedDelimiters,
+    /// The program ID is invalid, at the given context.
+    InvalidProgramID(String),
+    /// The function name is invalid, at the given context.
+    InvalidFunctionName(String),
+    /// The argument at the given index is invalid, at the given context.
+    InvalidArgument { index: usize, context: String },
+    /// The string is otherwise malformed, at the given context.
+    Malformed(String),
+}
+
+impl Display for FutureParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::UnbalancedDelimiters => write!(f, "Failed to parse future: unbalanced braces or brackets"),
+            Self::InvalidProgramID(context) => write!(f, "Failed to parse future: invalid program ID at \"{context}\""),
+            Self::InvalidFunctionName(context) => {
+                write!(f, "Failed to parse future: invalid function name at \"{context}\"")
+            }
+            Self::InvalidArgument { index, context } => {
+                write!(f, "Failed to parse future: invalid argument {index} at \"{context}\"")
+            }
+            Self::Malformed(context) => write!(f, "Failed to parse future: malformed at \"{context}\""),
+        }
+    }
+}
+
+impl std::error::Error for FutureParseError {}
+
+impl FutureParseError {
+    /// Returns the reason the given string fails to parse into a future.
+    fn diagnose<N: Network>(string: &str) -> Self {
+        /// Returns a short excerpt of the given string, for context.
+        fn context(string: &str) -> String {
+            string.trim_start().chars().take(32).collect()
+        }
+
+        /// Returns the string after the given field name and its ":".
+        fn skip_field<'a>(string: &'a str, field: &str) -> Option<&'a str> {
+            let string = &string[string.find(field)? + field.len()..];
+            string.trim_start().strip_prefix(':').map(str::trim_start)
+        }
+
+        // Ensure the braces and brackets are balanced, ignoring any within string literals.
+        let mut delimiters = Vec::new();
+        let mut in_string = false;
+        for character in string.chars() {
+            match character {
+                '"' => in_string = !in_string,
+                '{' | '[' if !in_string => delimiters.push(character),
+                '}' if !in_string && delimiters.pop() != Some('{') => return Self::UnbalancedDelimiters,
+                ']' if !in_string && delimiters.pop() != Some('[') => return Self::UnbalancedDelimiters,
+                _ => (),
+            }
+        }
+        if !delimiters.is_empty() {
+            return Self::UnbalancedDelimiters;
+        }
+
+        // Check the program ID.
+        let Some(string) = skip_field(string, "program_id") else { return Self::Malformed(context(string)) };
+        let Ok((string, _)) = ProgramID::<N>::parse(string) else { return Self::InvalidProgramID(context(string)) };
+        // Check the function name.
+        let Some(string) = skip_field(string, "function_name") else { return Self::Malformed(context(string)) };
+        let Ok((string, _)) = Identifier::<N>::parse(string) else { return Self::InvalidFunctionName(context(string)) };
+        // Check each argument.
+        let Some(string) = skip_field(string, "arguments") else { return Self::Malformed(context(string)) };
+        let Some(mut string) = string.strip_prefix('[') else { return Self::Malformed(context(string)) };
+        for index in 0.. {
+            string = string.trim_start();
+            if index == 0 && string.starts_with(']') {
+                break;
+            }
+            let remainder = match Future::<N>::parse(string) {
+                Ok((remainder, _)) => remainder,
+                Err(_) => match Plaintext::<N>::parse(string) {
+                    Ok((remainder, _)) => remainder,
+                    Err(_) => return Self::InvalidArgument { index, context: context(string) },
+                },
+            };
+            match remainder.trim_start().strip_prefix(',') {
+                Some(remainder) => string = remainder,
+                None => {
+                    string = remainder;
+                    break;
+                }
+            }
+        }
+        Self::Malformed(context(string))
+    }
+}
+
 impl<N: Network> Parser for Future<N> {
     /// Parses a string into a future value.
     #[inline]
@@ -104,15 +200,13 @@ impl<N: Network> FromStr for Future<N> {
     type Err = Error;
 
     /// Returns a future from a string literal.
+    /// On failure, the error is a `FutureParseError` describing the reason.
     fn from_str(string: &str) -> Result<Self> {
         match Self::parse(string) {
-            Ok((remainder, object)) => {
-                // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
-                // Return the object.
-                Ok(object)
-            }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            // Ensure the remainder is empty.
+            Ok((remainder, object)) if remainder.is_empty() => Ok(object),
+            // Otherwise, diagnose the failure, starting with unbalanced delimiters.
+            _ => Err(FutureParseError::diagnose::<N>(string).into()),
         }
     }
 }
@@ -127,13 +221,23 @@ impl<N: Network> Debug for Future<N> {
 impl<N: Network> Display for Future<N> {
     /// Prints the future as a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        self.fmt_internal(f, 0)
+        self.fmt_internal(f, 0, false)
     }
 }
 
 impl<N: Network> Future<N> {
+    /// Returns the future as a multi-line string, with nested struct and array arguments
+    /// indented to their depth within the future.
+    pub fn to_string_pretty(&self) -> String {
+        let mut string = String::new();
+        // Writing to a string is infallible.
+        let _ = self.fmt_internal(&mut string, 0, true);
+        string
+    }
+
     /// Prints the future with the given indentation depth.
-    fn fmt_internal(&self, f: &mut Formatter, depth: usize) -> fmt::Result {
+    /// If `pretty` is set, the plaintext arguments are also indented to the given depth.
+    fn fmt_internal(&self, f: &mut impl fmt::Write, depth: usize, pretty: bool) -> fmt::Result {
         /// The number of spaces to indent.
         const INDENT: usize = 2;
 
@@ -164,33 +268,42 @@ impl<N: Network> Future<N> {
             write!(f, "\n{:indent$}arguments: [", "", indent = (depth + 1) * INDENT)?;
             self.arguments.iter().enumerate().try_for_each(|(i, argument)| {
                 match argument {
-                    Argument::Plaintext(plaintext) => match i == self.arguments.len() - 1 {
-                        true => {
-                            // Print the last argument without a comma.
-                            write!(
-                                f,
-                                "\n{:indent$}{plaintext}",
-                                "",
-                                indent = (depth + 2) * INDENT,
-                                plaintext = plaintext
-                            )
-                        }
-                        // Print the argument with a comma.
-                        false => {
-                            write!(
-                                f,
-                                "\n{:indent$}{plaintext},",
-                                "",
-                                indent = (depth + 2) * INDENT,
-                                plaintext = plaintext
-                            )
+                    Argument::Plaintext(plaintext) => {
+                        // If pretty printing, indent the nested lines of the plaintext to the argument depth.
+                        let plaintext = match pretty {
+                            true => plaintext
+                                .to_string()
+                                .replace('\n', &format!("\n{:indent$}", "", indent = (depth + 2) * INDENT)),
+                            false => plaintext.to_string(),
+                        };
+                        match i == self.arguments.len() - 1 {
+                            true => {
+                                // Print the last argument without a comma.
+                                write!(
+                                    f,
+                                    "\n{:indent$}{plaintext}",
+                                    "",
+                                    indent = (depth + 2) * INDENT,
+                                    plaintext = plaintext
+                                )
+                            }
+                            // Print the argument with a comma.
+                            false => {
+                                write!(
+                                    f,
+                                    "\n{:indent$}{plaintext},",
+                                    "",
+                                    indent = (depth + 2) * INDENT,
+                                    plaintext = plaintext
+                                )
+                            }
                         }
-                    },
+                    }
                     Argument::Future(future) => {
                         // Print a newline.
                         write!(f, "\n{:indent$}", "", indent = (depth + 2) * INDENT)?;
                         // Print the argument.
-                        future.fmt_internal(f, depth + 2)?;
+                        future.fmt_internal(f, depth + 2, pretty)?;
                         // Print the closing brace.
                         match i == self.arguments.len() - 1 {
                             // Print the last member without a comma.
@@ -249,4 +362,114 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_round_trip() -> Result<()> {
+        let string = "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [ 1u64 ] }, { a: 1u8, b: [ true, false ] }, 5u64 ] }";
+
+        // Ensure the future survives a round trip through its string representation.
+        let expected = Future::<CurrentNetwork>::from_str(string)?;
+        let candidate = Future::<CurrentNetwork>::from_str(&expected.to_string())?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected.to_string(), candidate.to_string());
+
+        // Ensure trailing characters are rejected.
+        assert!(Future::<CurrentNetwork>::from_str(&format!("{string} foo")).is_err());
+
+        // Ensure malformed program IDs are rejected.
+        let string = "{ program_id: credits, function_name: transfer, arguments: [] }";
+        assert!(Future::<CurrentNetwork>::from_str(string).is_err());
+        let string = "{ program_id: 1credits.aleo, function_name: transfer, arguments: [] }";
+        assert!(Future::<CurrentNetwork>::from_str(string).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_string_pretty() -> Result<()> {
+        let expected = r"{
+  program_id: credits.aleo,
+  function_name: transfer,
+  arguments: [
+    {
+      program_id: token.aleo,
+      function_name: mint,
+      arguments: [
+        {
+          c: 2u64
+        }
+      ]
+    },
+    {
+      a: 1u8,
+      b: [
+        true,
+        false
+      ]
+    }
+  ]
+}";
+        let candidate = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [ { c: 2u64 } ] }, { a: 1u8, b: [ true, false ] } ] }",
+        )?;
+        assert_eq!(expected, candidate.to_string_pretty());
+        // Ensure the pretty string parses back into the same future.
+        assert_eq!(candidate, Future::from_str(&candidate.to_string_pretty())?);
+
+        // Ensure the pretty string matches the compact string when there are only literal arguments.
+        let candidate = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64, { program_id: token.aleo, function_name: mint, arguments: [ 2u64 ] } ] }",
+        )?;
+        assert_eq!(candidate.to_string(), candidate.to_string_pretty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() -> Result<()> {
+        /// Returns the reason the given string fails to parse.
+        fn parse_error(string: &str) -> FutureParseError {
+            let error = Future::<CurrentNetwork>::from_str(string).unwrap_err();
+            error.downcast_ref::<FutureParseError>().unwrap().clone()
+        }
+
+        // Check unbalanced delimiters.
+        for string in [
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64 }",
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { a: 1u8 ] }",
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [] }}",
+        ] {
+            assert_eq!(parse_error(string), FutureParseError::UnbalancedDelimiters);
+        }
+
+        // Check an invalid program ID.
+        let string = "{ program_id: 1credits.aleo, function_name: transfer, arguments: [] }";
+        assert!(
+            matches!(parse_error(string), FutureParseError::InvalidProgramID(context) if context.starts_with("1credits"))
+        );
+
+        // Check an invalid function name.
+        let string = "{ program_id: credits.aleo, function_name: _transfer, arguments: [] }";
+        assert!(
+            matches!(parse_error(string), FutureParseError::InvalidFunctionName(context) if context.starts_with("_transfer"))
+        );
+
+        // Check an invalid argument, including within a nested future.
+        let string = "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64, 2u65 ] }";
+        assert!(
+            matches!(parse_error(string), FutureParseError::InvalidArgument { index: 1, context } if context.starts_with("2u65"))
+        );
+        let string = "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [ 2u65 ] } ] }";
+        assert!(matches!(parse_error(string), FutureParseError::InvalidArgument { index: 0, .. }));
+
+        // Check malformed strings.
+        let string = "{ program_id: credits.aleo, arguments: [] }";
+        assert!(matches!(parse_error(string), FutureParseError::Malformed(..)));
+        let string = "{ program_id: credits.aleo, function_name: transfer, arguments: [] } foo";
+        assert!(matches!(parse_error(string), FutureParseError::Malformed(context) if context.contains("foo")));
+        let string = "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64 ] } foo";
+        assert!(matches!(parse_error(string), FutureParseError::Malformed(context) if context.starts_with("] } foo")));
+
+        Ok(())
+    }
 }
@@ -33,3 +33,65 @@ impl<'de, N: Network> Deserialize<'de> for Future<N> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Returns a set of futures with zero, many, and deeply-nested arguments.
+    fn sample_futures() -> Result<Vec<Future<CurrentNetwork>>> {
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let function_name = Identifier::from_str("transfer")?;
+
+        // Sample a future with no arguments.
+        let empty = Future::new(program_id, function_name, vec![]);
+        // Sample a future with many arguments.
+        let arguments = (0..16u64)
+            .map(|i| Ok(Argument::Plaintext(Plaintext::from_str(&format!("{i}u64"))?)))
+            .collect::<Result<Vec<_>>>()?;
+        let wide = Future::new(program_id, function_name, arguments);
+        // Sample a future with deeply-nested futures, structs, and arrays.
+        let plaintext = Plaintext::from_str("{ a: [ { b: 1u8 }, { b: 2u8 } ], c: { d: [ true, false ] } }")?;
+        let mut deep = Future::new(program_id, function_name, vec![Argument::Plaintext(plaintext)]);
+        for _ in 0..8 {
+            let arguments = vec![Argument::Plaintext(Plaintext::from_str("5field")?), Argument::Future(deep)];
+            deep = Future::new(program_id, function_name, arguments);
+        }
+
+        Ok(vec![empty, wide, deep])
+    }
+
+    #[test]
+    fn test_serialization_round_trips() -> Result<()> {
+        for expected in sample_futures()? {
+            // Check the bytes round trip.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, Future::read_le(&expected_bytes[..])?);
+
+            // Check the string round trip.
+            assert_eq!(expected, Future::from_str(&expected.to_string())?);
+
+            // Check the bits agree with their size. `Future` does not implement `FromBits` yet,
+            // so the bits cannot be round tripped (see the TODO in `future/mod.rs`).
+            assert_eq!(expected.to_bits_le().len(), expected.size_in_bits());
+            assert_eq!(expected.to_bits_be().len(), expected.size_in_bits());
+
+            // Check the serde JSON round trip.
+            let candidate_string = serde_json::to_string(&expected)?;
+            assert_eq!(expected.to_string(), serde_json::Value::from_str(&candidate_string)?.as_str().unwrap());
+            assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+            // Check the structured JSON round trip.
+            assert_eq!(expected, Future::from_json(&expected.to_json()?)?);
+
+            // Check the serde bincode round trip.
+            let candidate_bytes = bincode::serialize(&expected)?;
+            assert_eq!(&expected_bytes[..], &candidate_bytes[8..]);
+            assert_eq!(expected, bincode::deserialize(&candidate_bytes[..])?);
+        }
+        Ok(())
+    }
+}
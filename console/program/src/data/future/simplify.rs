@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Future<N> {
+    /// Returns a simplified copy of the future, for display and comparison tooling.
+    /// Every struct with a single member is collapsed into the value of that member, recursively.
+    ///
+    /// Note: This is lossy, as the simplified future no longer matches the types of the called function.
+    pub fn simplify(&self) -> Future<N> {
+        let arguments = self
+            .arguments
+            .iter()
+            .map(|argument| match argument {
+                Argument::Plaintext(plaintext) => Argument::Plaintext(simplify_plaintext(plaintext)),
+                Argument::Future(future) => Argument::Future(future.simplify()),
+            })
+            .collect();
+        Self::new(self.program_id, self.function_name, arguments)
+    }
+}
+
+/// Returns the simplified plaintext, in which every struct with a single member is collapsed into that member.
+fn simplify_plaintext<N: Network>(plaintext: &Plaintext<N>) -> Plaintext<N> {
+    match plaintext {
+        Plaintext::Literal(..) => plaintext.clone(),
+        Plaintext::Struct(members, ..) => match members.len() == 1 {
+            true => simplify_plaintext(&members[0]),
+            false => Plaintext::Struct(
+                members.iter().map(|(name, member)| (*name, simplify_plaintext(member))).collect(),
+                Default::default(),
+            ),
+        },
+        Plaintext::Array(elements, ..) => {
+            Plaintext::Array(elements.iter().map(simplify_plaintext).collect(), Default::default())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_simplify() -> Result<()> {
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { amount: 5u64 }, { a: 1u8, b: { c: true } }, [ { d: 2u8 } ], { program_id: token.aleo, function_name: mint, arguments: [ { e: { f: 3u8 } } ] } ] }",
+        )?;
+
+        // Ensure single-member structs are collapsed, while multi-member structs are untouched.
+        let expected = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 5u64, { a: 1u8, b: true }, [ 2u8 ], { program_id: token.aleo, function_name: mint, arguments: [ 3u8 ] } ] }",
+        )?;
+        assert_eq!(future.simplify(), expected);
+
+        // Ensure a future without single-member structs is unchanged.
+        assert_eq!(expected.simplify(), expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Future<N> {
+    /// Returns the number of bits in the **little-endian** bit representation of `self`,
+    /// without materializing the bits.
+    pub fn size_in_bits(&self) -> usize {
+        /// Returns the number of bits in the bit representation of the given plaintext.
+        fn plaintext_size_in_bits<N: Network>(plaintext: &Plaintext<N>) -> usize {
+            // Account for the variant bits.
+            2 + match plaintext {
+                // Account for the literal variant, the literal size, and the literal.
+                Plaintext::Literal(literal, ..) => 8 + 16 + literal.size_in_bits() as usize,
+                // Account for the number of members, and the identifier, size, and value of each member.
+                Plaintext::Struct(members, ..) => {
+                    8 + members
+                        .iter()
+                        .map(|(identifier, member)| {
+                            8 + identifier.size_in_bits() as usize + 16 + plaintext_size_in_bits(member)
+                        })
+                        .sum::<usize>()
+                }
+                // Account for the number of elements, and the size and value of each element.
+                Plaintext::Array(elements, ..) => {
+                    32 + elements.iter().map(|element| 16 + plaintext_size_in_bits(element)).sum::<usize>()
+                }
+            }
+        }
+
+        // Account for the size and bits of the program ID.
+        let program_id_size =
+            self.program_id.name().size_in_bits() as usize + self.program_id.network().size_in_bits() as usize;
+        // Account for the size and bits of the function name.
+        let function_name_size = self.function_name.size_in_bits() as usize;
+        // Account for the size, variant, and bits of each argument.
+        let arguments_size = self
+            .arguments
+            .iter()
+            .map(|argument| {
+                16 + 1
+                    + match argument {
+                        Argument::Plaintext(plaintext) => plaintext_size_in_bits(plaintext),
+                        Argument::Future(future) => future.size_in_bits(),
+                    }
+            })
+            .sum::<usize>();
+        // Account for the number of arguments.
+        16 + program_id_size + 16 + function_name_size + 8 + arguments_size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_size_in_bits() -> Result<()> {
+        for string in [
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [] }",
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64, 2field, true, -5i8 ] }",
+            "{ program_id: credits.aleo, function_name: transfer_public, arguments: [ aleo1g8qul5a44vk22u9uuvaewdcjw4v6xg8wx0llru39nnjn7eu08yrscxe4e2, 100000000u64 ] }",
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { a: 1u8, bb: [ 1u8, 2u8 ] }, [ [ 1u16 ], [ 2u16 ] ], 5u64 ] }",
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [ 1u64, { program_id: token.aleo, function_name: burn, arguments: [ { a: 1scalar } ] } ] }, 5u64 ] }",
+        ] {
+            let future = Future::<CurrentNetwork>::from_str(string)?;
+            assert_eq!(future.to_bits_le().len(), future.size_in_bits());
+            assert_eq!(future.to_bits_be().len(), future.size_in_bits());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Future<N> {
+    /// Returns the number of field elements to encode `self`.
+    pub fn size_in_fields(&self) -> Result<u16> {
+        // Compute the number of bits, with 1 extra bit for the terminus indicator.
+        let num_bits = self.size_in_bits() + 1;
+        // Compute the ceiling division of the number of bits by the number of bits in a field element.
+        let num_fields = (num_bits + Field::<N>::size_in_data_bits() - 1) / Field::<N>::size_in_data_bits();
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        match num_fields <= N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            // Return the number of field elements.
+            true => Ok(u16::try_from(num_fields).or_halt_with::<N>("Future exceeds u16::MAX field elements.")),
+            false => bail!("Future cannot exceed {} field elements.", N::MAX_DATA_SIZE_IN_FIELDS),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_size_in_fields() -> Result<()> {
+        for string in [
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [] }",
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64, 2field, true ] }",
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { a: 1u8, b: [ 1u8, 2u8 ] }, 5u64 ] }",
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [ 1u64 ] }, 5u64 ] }",
+        ] {
+            let future = Future::<CurrentNetwork>::from_str(string)?;
+            assert_eq!(future.to_fields()?.len(), future.size_in_fields()? as usize);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Future<N> {
+    /// Returns a Graphviz DOT representation of the future, as a tree of its nested futures.
+    /// Each node is labeled with the call target of its future, e.g. `credits.aleo/transfer`.
+    pub fn to_dot(&self) -> String {
+        let mut output = String::from("digraph future {\n");
+        let mut num_nodes = 0;
+        self.write_dot_nodes(&mut output, &mut num_nodes);
+        output.push('}');
+        output
+    }
+
+    /// Writes the node for this future and its nested futures, and the edges between them.
+    /// Returns the index of the node for this future.
+    fn write_dot_nodes(&self, output: &mut String, num_nodes: &mut usize) -> usize {
+        // Write the node for this future.
+        let index = *num_nodes;
+        *num_nodes += 1;
+        output.push_str(&format!("  n{index} [label=\"{}/{}\"];\n", self.program_id, self.function_name));
+        // Write the nodes for the nested futures, along with an edge to each of them.
+        for argument in self.arguments.iter() {
+            if let Argument::Future(future) = argument {
+                let child = future.write_dot_nodes(output, num_nodes);
+                output.push_str(&format!("  n{index} -> n{child};\n"));
+            }
+        }
+        index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_to_dot() -> Result<()> {
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 5u64, { program_id: token.aleo, function_name: mint, arguments: [ { program_id: credits.aleo, function_name: burn, arguments: [] } ] }, { program_id: token.aleo, function_name: burn, arguments: [] } ] }",
+        )?;
+        let dot = future.to_dot();
+
+        assert!(dot.starts_with("digraph future {"));
+        assert!(dot.ends_with('}'));
+        // Ensure there is a node per future.
+        assert_eq!(dot.matches("[label=").count(), 4);
+        assert!(dot.contains("n0 [label=\"credits.aleo/transfer\"];"));
+        assert!(dot.contains("n1 [label=\"token.aleo/mint\"];"));
+        assert!(dot.contains("n2 [label=\"credits.aleo/burn\"];"));
+        assert!(dot.contains("n3 [label=\"token.aleo/burn\"];"));
+        // Ensure the edges follow the nesting of the futures.
+        assert!(dot.contains("n0 -> n1;"));
+        assert!(dot.contains("n1 -> n2;"));
+        assert!(dot.contains("n0 -> n3;"));
+        assert_eq!(dot.matches("->").count(), 3);
+
+        Ok(())
+    }
+}
@@ -18,6 +18,9 @@ impl<N: Network> ToFields for Future<N> {
     type Field = Field<N>;
 
     /// Returns the future as a list of fields.
+    ///
+    /// The fields pack the program ID, the function name, and then each argument, in that order.
+    /// This ordering is relied upon by the future circuit, and must not change.
     #[inline]
     fn to_fields(&self) -> Result<Vec<Self::Field>> {
         // Encode the data as little-endian bits.
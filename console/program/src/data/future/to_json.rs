@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Future<N> {
+    /// Returns the future as a JSON value, of the form `{"program_id":...,"function_name":...,"arguments":[...]}`.
+    /// Plaintext arguments use their serde representation, and nested futures are converted recursively.
+    pub fn to_json(&self) -> Result<serde_json::Value> {
+        let arguments = self
+            .arguments
+            .iter()
+            .map(|argument| match argument {
+                Argument::Plaintext(plaintext) => Ok(serde_json::to_value(plaintext)?),
+                Argument::Future(future) => future.to_json(),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(serde_json::json!({
+            "program_id": self.program_id.to_string(),
+            "function_name": self.function_name.to_string(),
+            "arguments": arguments,
+        }))
+    }
+
+    /// Returns a future from a JSON value, of the form produced by `Future::to_json`.
+    pub fn from_json(json: &serde_json::Value) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = match json.get("program_id").and_then(|program_id| program_id.as_str()) {
+            Some(program_id) => ProgramID::from_str(program_id)?,
+            None => bail!("Failed to parse the future JSON: missing the program ID"),
+        };
+        // Retrieve the function name.
+        let function_name = match json.get("function_name").and_then(|function_name| function_name.as_str()) {
+            Some(function_name) => Identifier::from_str(function_name)?,
+            None => bail!("Failed to parse the future JSON: missing the function name"),
+        };
+        // Retrieve the arguments.
+        let arguments = match json.get("arguments").and_then(|arguments| arguments.as_array()) {
+            Some(arguments) => arguments
+                .iter()
+                .map(|argument| match argument.is_object() {
+                    true => Ok(Argument::Future(Future::from_json(argument)?)),
+                    false => Ok(Argument::Plaintext(serde_json::from_value(argument.clone())?)),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => bail!("Failed to parse the future JSON: missing the arguments"),
+        };
+        Ok(Self::new(program_id, function_name, arguments))
+    }
+
+    /// Returns the future as a JSON value, annotating each argument with its type.
+    /// A literal argument is annotated with its literal type, e.g. `{"type":"u64","value":"5u64"}`,
+    /// and a nested future is annotated as `{"type":"future","value":{...}}`.
+    pub fn to_annotated_json(&self) -> serde_json::Value {
+        let arguments = self
+            .arguments
+            .iter()
+            .map(|argument| match argument {
+                Argument::Plaintext(plaintext) => {
+                    let type_name = match plaintext {
+                        Plaintext::Literal(literal, ..) => literal.to_type().type_name().to_string(),
+                        Plaintext::Struct(..) => "struct".to_string(),
+                        Plaintext::Array(..) => "array".to_string(),
+                    };
+                    serde_json::json!({ "type": type_name, "value": plaintext.to_string() })
+                }
+                Argument::Future(future) => {
+                    serde_json::json!({ "type": "future", "value": future.to_annotated_json() })
+                }
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::json!({
+            "program_id": self.program_id.to_string(),
+            "function_name": self.function_name.to_string(),
+            "arguments": arguments,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_json_round_trip() -> Result<()> {
+        let expected = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 5u64, { a: 1u8, b: [ true, false ] }, { program_id: token.aleo, function_name: mint, arguments: [ aleo1g8qul5a44vk22u9uuvaewdcjw4v6xg8wx0llru39nnjn7eu08yrscxe4e2 ] } ] }",
+        )?;
+        let json = expected.to_json()?;
+        assert_eq!(json["program_id"], "credits.aleo");
+        assert_eq!(json["function_name"], "transfer");
+        assert_eq!(json["arguments"][0], "5u64");
+        assert_eq!(json["arguments"][2]["program_id"], "token.aleo");
+
+        // Ensure the future survives a round trip through a JSON string.
+        let candidate = Future::<CurrentNetwork>::from_json(&serde_json::from_str(&serde_json::to_string(&json)?)?)?;
+        assert_eq!(expected, candidate);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_json_malformed() {
+        let check_error = |json: serde_json::Value| assert!(Future::<CurrentNetwork>::from_json(&json).is_err());
+
+        // Check a malformed program ID.
+        check_error(serde_json::json!({ "program_id": "credits", "function_name": "transfer", "arguments": [] }));
+        // Check a malformed function name.
+        check_error(serde_json::json!({ "program_id": "credits.aleo", "function_name": "1transfer", "arguments": [] }));
+        // Check a malformed argument.
+        check_error(
+            serde_json::json!({ "program_id": "credits.aleo", "function_name": "transfer", "arguments": ["5"] }),
+        );
+        // Check a malformed nested future.
+        check_error(serde_json::json!({
+            "program_id": "credits.aleo",
+            "function_name": "transfer",
+            "arguments": [ { "program_id": "token", "function_name": "mint", "arguments": [] } ]
+        }));
+        // Check missing fields.
+        check_error(serde_json::json!({ "function_name": "transfer", "arguments": [] }));
+        check_error(serde_json::json!({ "program_id": "credits.aleo", "arguments": [] }));
+        check_error(serde_json::json!({ "program_id": "credits.aleo", "function_name": "transfer" }));
+    }
+
+    #[test]
+    fn test_to_annotated_json() -> Result<()> {
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 5u64, { a: 1u8 }, { program_id: token.aleo, function_name: mint, arguments: [ true ] } ] }",
+        )?;
+        let json = future.to_annotated_json();
+
+        assert_eq!(json["program_id"], "credits.aleo");
+        assert_eq!(json["function_name"], "transfer");
+
+        // Check the literal argument.
+        assert_eq!(json["arguments"][0]["type"], "u64");
+        assert_eq!(json["arguments"][0]["value"], "5u64");
+        // Check the struct argument.
+        assert_eq!(json["arguments"][1]["type"], "struct");
+        assert_eq!(json["arguments"][1]["value"], Plaintext::<CurrentNetwork>::from_str("{ a: 1u8 }")?.to_string());
+        // Check the nested future argument.
+        assert_eq!(json["arguments"][2]["type"], "future");
+        assert_eq!(json["arguments"][2]["value"]["program_id"], "token.aleo");
+        assert_eq!(json["arguments"][2]["value"]["arguments"][0]["type"], "boolean");
+        assert_eq!(json["arguments"][2]["value"]["arguments"][0]["value"], "true");
+
+        Ok(())
+    }
+}
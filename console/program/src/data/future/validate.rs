@@ -0,0 +1,213 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::PlaintextType;
+
+impl<N: Network> Future<N> {
+    /// Returns the nesting depth of the future, which is 1 for a future without nested futures.
+    /// Consistent with serialization, up to `Self::MAX_DEPTH` levels of futures may be nested
+    /// beneath the top-level future, and an error is returned if this is exceeded.
+    pub fn depth(&self) -> Result<usize> {
+        self.depth_bounded(Self::MAX_DEPTH.saturating_add(1))
+    }
+
+    /// Returns the nesting depth of the future, or an error if it exceeds the given maximum depth.
+    pub fn depth_bounded(&self, max_depth: usize) -> Result<usize> {
+        /// Returns the depth of the given future, which is nested at the given depth.
+        fn depth_internal<N: Network>(future: &Future<N>, depth: usize, max_depth: usize) -> Result<usize> {
+            // Ensure the future does not exceed the maximum depth.
+            ensure!(depth <= max_depth, "Future exceeds the maximum depth of {max_depth}");
+            // Recurse into each nested future.
+            future.arguments.iter().try_fold(depth, |max, argument| match argument {
+                Argument::Future(future) => Ok(max.max(depth_internal(future, depth + 1, max_depth)?)),
+                Argument::Plaintext(..) => Ok(max),
+            })
+        }
+
+        depth_internal(self, 1, max_depth)
+    }
+
+    /// Ensures the arguments of the future match the given input types of the called function.
+    /// As struct definitions are not available here, struct arguments are only checked to be structs.
+    pub fn matches(&self, expected: &[PlaintextType<N>]) -> Result<()> {
+        /// Ensures the given plaintext matches the given plaintext type.
+        fn matches_plaintext<N: Network>(plaintext: &Plaintext<N>, plaintext_type: &PlaintextType<N>) -> bool {
+            match (plaintext, plaintext_type) {
+                (Plaintext::Literal(literal, ..), PlaintextType::Literal(literal_type)) => {
+                    literal.to_type() == *literal_type
+                }
+                (Plaintext::Struct(..), PlaintextType::Struct(..)) => true,
+                (Plaintext::Array(elements, ..), PlaintextType::Array(array_type)) => {
+                    elements.len() == **array_type.length() as usize
+                        && elements.iter().all(|element| matches_plaintext(element, array_type.next_element_type()))
+                }
+                _ => false,
+            }
+        }
+
+        // Ensure the number of arguments matches the number of expected types.
+        ensure!(
+            self.arguments.len() == expected.len(),
+            "Future '{}/{}' expects {} arguments, but found {}",
+            self.program_id,
+            self.function_name,
+            expected.len(),
+            self.arguments.len()
+        );
+        // Ensure each argument matches its expected type.
+        for (index, (argument, plaintext_type)) in self.arguments.iter().zip_eq(expected).enumerate() {
+            match argument {
+                Argument::Plaintext(plaintext) => ensure!(
+                    matches_plaintext(plaintext, plaintext_type),
+                    "Argument {index} of future '{}/{}' is '{plaintext}', which is not of type '{plaintext_type}'",
+                    self.program_id,
+                    self.function_name
+                ),
+                Argument::Future(..) => bail!(
+                    "Argument {index} of future '{}/{}' is a future, which is not of type '{plaintext_type}'",
+                    self.program_id,
+                    self.function_name
+                ),
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures that no nested future has the same call target as any of its enclosing futures.
+    pub fn assert_acyclic(&self) -> Result<()> {
+        /// Ensures the given future does not have the same call target as any of its `ancestors`.
+        fn check_acyclic<N: Network>(
+            future: &Future<N>,
+            ancestors: &mut Vec<(ProgramID<N>, Identifier<N>)>,
+        ) -> Result<()> {
+            let target = (*future.program_id(), *future.function_name());
+            // Ensure the call target does not appear in the ancestors.
+            ensure!(
+                !ancestors.contains(&target),
+                "Future '{}/{}' is nested within a future with the same call target",
+                target.0,
+                target.1
+            );
+            // Check each nested future.
+            ancestors.push(target);
+            for argument in future.arguments() {
+                if let Argument::Future(future) = argument {
+                    check_acyclic(future, ancestors)?;
+                }
+            }
+            ancestors.pop();
+            Ok(())
+        }
+
+        check_acyclic(self, &mut Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_assert_acyclic() -> Result<()> {
+        // Check a future nesting a different call target.
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: transfer, arguments: [ 1u64 ] }, { program_id: credits.aleo, function_name: mint, arguments: [] } ] }",
+        )?;
+        assert!(future.assert_acyclic().is_ok());
+
+        // Check a future nesting its own call target.
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: credits.aleo, function_name: transfer, arguments: [ 1u64 ] } ] }",
+        )?;
+        assert!(future.assert_acyclic().is_err());
+
+        // Check a future nesting its own call target more deeply.
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [ { program_id: credits.aleo, function_name: transfer, arguments: [] } ] } ] }",
+        )?;
+        assert!(future.assert_acyclic().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches() -> Result<()> {
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64, [ true, false ], { a: 1u8 } ] }",
+        )?;
+        let types = |string: &str| -> Result<Vec<PlaintextType<CurrentNetwork>>> {
+            string.split(", ").map(PlaintextType::from_str).collect()
+        };
+
+        // Ensure matching types succeed.
+        assert!(future.matches(&types("u64, [boolean; 2u32], foo")?).is_ok());
+
+        // Ensure a length mismatch fails.
+        assert!(future.matches(&types("u64, [boolean; 2u32]")?).is_err());
+        assert!(future.matches(&types("u64, [boolean; 2u32], foo, u8")?).is_err());
+        assert!(future.matches(&[]).is_err());
+
+        // Ensure a single mismatched type fails.
+        assert!(future.matches(&types("u32, [boolean; 2u32], foo")?).is_err());
+        assert!(future.matches(&types("u64, [boolean; 3u32], foo")?).is_err());
+        assert!(future.matches(&types("u64, [u8; 2u32], foo")?).is_err());
+        assert!(future.matches(&types("u64, [boolean; 2u32], u8")?).is_err());
+
+        // Ensure a nested future does not match a plaintext type.
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [] } ] }",
+        )?;
+        assert!(future.matches(&types("u64")?).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_depth() -> Result<()> {
+        // Check a future with only literal arguments.
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ 1u64, { a: [ 1u8 ] } ] }",
+        )?;
+        assert_eq!(future.depth()?, 1);
+
+        // Check a future nested 3 levels deep, alongside a shallower nested future.
+        let future = Future::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [] }, { program_id: token.aleo, function_name: burn, arguments: [ { program_id: token.aleo, function_name: mint, arguments: [ 1u64 ] } ] } ] }",
+        )?;
+        assert_eq!(future.depth()?, 3);
+
+        // Ensure a future over the given limit is rejected.
+        assert_eq!(future.depth_bounded(3)?, 3);
+        assert!(future.depth_bounded(2).is_err());
+        assert!(future.depth_bounded(0).is_err());
+
+        // Ensure a future over the default limit is rejected, consistent with serialization.
+        let program_id = *future.program_id();
+        let function_name = *future.function_name();
+        let mut future = Future::<CurrentNetwork>::empty(program_id, function_name);
+        for _ in 0..Future::<CurrentNetwork>::MAX_DEPTH {
+            future = Future::new(program_id, function_name, vec![Argument::Future(future)]);
+        }
+        assert_eq!(future.depth()?, Future::<CurrentNetwork>::MAX_DEPTH + 1);
+        let future = Future::new(program_id, function_name, vec![Argument::Future(future)]);
+        assert!(future.depth().is_err());
+        assert!(future.to_bytes_le().is_err());
+
+        Ok(())
+    }
+}
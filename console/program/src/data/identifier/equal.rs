@@ -29,6 +29,20 @@ impl<N: Network> core::hash::Hash for Identifier<N> {
     }
 }
 
+impl<N: Network> Ord for Identifier<N> {
+    /// Ordering is determined by the string representation of the identifier.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_string().cmp(&other.to_string())
+    }
+}
+
+impl<N: Network> PartialOrd for Identifier<N> {
+    /// Ordering is determined by the string representation of the identifier.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl<N: Network> Equal<Self> for Identifier<N> {
     type Output = Boolean<N>;
 
@@ -19,7 +19,7 @@ mod ciphertext;
 pub use ciphertext::Ciphertext;
 
 mod future;
-pub use future::{Argument, Future};
+pub use future::{group_futures_by_target, Argument, Future, FutureComparison, FutureParseError, FuturesByTarget};
 
 pub(super) mod identifier;
 pub use identifier::Identifier;
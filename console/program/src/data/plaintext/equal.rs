@@ -23,6 +23,19 @@ impl<N: Network> PartialEq for Plaintext<N> {
     }
 }
 
+impl<N: Network> core::hash::Hash for Plaintext<N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Literal(literal, _) => literal.hash(state),
+            Self::Struct(members, _) => members.iter().for_each(|(name, plaintext)| {
+                name.hash(state);
+                plaintext.hash(state);
+            }),
+            Self::Array(elements, _) => elements.hash(state),
+        }
+    }
+}
+
 impl<N: Network> Equal<Self> for Plaintext<N> {
     type Output = Boolean<N>;
 
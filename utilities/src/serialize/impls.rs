@@ -200,7 +200,7 @@ impl<T: CanonicalSerialize> CanonicalSerialize for Option<T> {
 
     #[inline]
     fn serialized_size(&self, compress: Compress) -> usize {
-        8 + self.as_ref().map(|s| s.serialized_size(compress)).unwrap_or(0)
+        self.is_some().serialized_size(compress) + self.as_ref().map(|s| s.serialized_size(compress)).unwrap_or(0)
     }
 }
 
@@ -713,6 +713,15 @@ mod test {
     fn test_option() {
         test_serialize(Some(3u32));
         test_serialize(None::<u32>);
+
+        // Ensure the serialized size accounts for the presence flag as a single byte.
+        for compress in [Compress::No, Compress::Yes] {
+            for data in [Some(3u32), None] {
+                let mut serialized = Vec::new();
+                data.serialize_with_mode(&mut serialized, compress).unwrap();
+                assert_eq!(serialized.len(), data.serialized_size(compress));
+            }
+        }
     }
 
     #[test]